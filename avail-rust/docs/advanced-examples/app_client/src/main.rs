@@ -0,0 +1,25 @@
+use avail_rust::{AppClient, Keypair, SecretUri, SDK};
+use core::str::FromStr;
+
+#[tokio::main]
+async fn main() -> Result<(), String> {
+	let sdk = SDK::new("ws://127.0.0.1:9944").await.unwrap();
+
+	// Input
+	let secret_uri = SecretUri::from_str("//Alice").unwrap();
+	let account = Keypair::from_uri(&secret_uri).unwrap();
+	let app_id = 1;
+
+	sdk.util.validate_app_id(app_id).await?;
+	let client = AppClient::new(&sdk, app_id, account);
+
+	for data in ["First", "Second", "Third"] {
+		let result = client.submit(data.as_bytes().to_vec()).await?;
+		println!(
+			"BlockHash={:?}, TxIndex={}, DataHash={:?}",
+			result.block_hash, result.tx_index, result.event.data_hash
+		);
+	}
+
+	Ok(())
+}
@@ -0,0 +1,55 @@
+use avail_rust::{
+	subxt::backend::rpc::{RawRpcFuture, RawRpcSubscription, RpcClientT},
+	ClientOptions, SDK,
+};
+use jsonrpsee::ws_client::{WsClient, WsClientBuilder};
+use serde_json::value::RawValue;
+
+// Any type implementing `RpcClientT` can be used as a transport. This one forwards everything
+// to a WebSocket client and prints the method names, but it could just as well talk to a
+// message bus.
+struct LoggingTransport(WsClient);
+
+impl RpcClientT for LoggingTransport {
+	fn request_raw<'a>(
+		&'a self,
+		method: &'a str,
+		params: Option<Box<RawValue>>,
+	) -> RawRpcFuture<'a, Box<RawValue>> {
+		println!("Request={}", method);
+		self.0.request_raw(method, params)
+	}
+
+	fn subscribe_raw<'a>(
+		&'a self,
+		sub: &'a str,
+		params: Option<Box<RawValue>>,
+		unsub: &'a str,
+	) -> RawRpcFuture<'a, RawRpcSubscription> {
+		println!("Subscribe={}", sub);
+		self.0.subscribe_raw(sub, params, unsub)
+	}
+}
+
+#[tokio::main]
+async fn main() -> Result<(), String> {
+	let endpoint = "ws://127.0.0.1:9944";
+	let client = WsClientBuilder::default()
+		.build(endpoint)
+		.await
+		.map_err(|e| e.to_string())?;
+
+	let sdk = SDK::with_transport(LoggingTransport(client), endpoint, ClientOptions::new())
+		.await
+		.map_err(|e| e.to_string())?;
+
+	let block_hash = sdk
+		.rpc
+		.chain
+		.get_finalized_head()
+		.await
+		.map_err(|e| e.to_string())?;
+	println!("FinalizedHead={:?}", block_hash);
+
+	Ok(())
+}
@@ -0,0 +1,47 @@
+use avail_rust::{
+	avail, utils_raw, AvailExtrinsicParamsBuilder, Data, Keypair, SecretUri, WaitFor, SDK,
+};
+use core::str::FromStr;
+
+#[tokio::main]
+async fn main() -> Result<(), String> {
+	let sdk = SDK::new("ws://127.0.0.1:9944").await.unwrap();
+
+	// Input
+	let secret_uri = SecretUri::from_str("//Alice").unwrap();
+	let account = Keypair::from_uri(&secret_uri).unwrap();
+	let account_id = account.public_key().to_account_id();
+	let data = String::from("My Awesome Data").as_bytes().to_vec();
+	let data = Data { 0: data };
+
+	let call = avail::tx().data_availability().submit_data(data);
+	let params = AvailExtrinsicParamsBuilder::new().app_id(1).build();
+	let partial = sdk
+		.api
+		.tx()
+		.create_partial_signed(&call, &account_id, params)
+		.await
+		.map_err(|e| e.to_string())?;
+
+	// These are the bytes that need to be signed. Payloads longer than 256 bytes are
+	// already replaced by their blake2-256 hash.
+	let payload = partial.signer_payload();
+
+	// The payload can be handed to a hardware wallet or a remote signer which returns the
+	// raw signature bytes. Here a local keypair is used instead.
+	let signature_bytes = account.sign(&payload).0;
+	let signature = utils_raw::signature_from_bytes(&signature_bytes)?;
+
+	// The signature is checked locally so a faulty signer does not cost a round trip.
+	let tx = utils_raw::sign_with_verified_signature(&partial, &account_id, &signature)?;
+	let maybe_tx_progress = tx.submit_and_watch().await;
+	let tx_in_block = sdk
+		.util
+		.progress_transaction(maybe_tx_progress, WaitFor::BlockInclusion)
+		.await?;
+
+	println!("BlockHash={:?}", tx_in_block.block_hash());
+	println!("ExtrinsicHash={:?}", tx_in_block.extrinsic_hash());
+
+	Ok(())
+}
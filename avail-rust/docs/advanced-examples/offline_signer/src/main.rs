@@ -0,0 +1,66 @@
+use avail_rust::{
+	avail, utils_raw, AccountId, Data, Keypair, OfflineMortality, OfflineParams, SecretUri,
+	WaitFor, SDK,
+};
+use core::str::FromStr;
+
+#[tokio::main]
+async fn main() -> Result<(), String> {
+	// Online machine: collect everything the offline machine needs.
+	let sdk = SDK::new("ws://127.0.0.1:9944").await.unwrap();
+	let account_id =
+		AccountId::from_str("5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY").unwrap(); // Alice
+	let runtime_version = sdk.api.runtime_version();
+	let block_hash = sdk
+		.rpc
+		.chain
+		.get_block_hash(None)
+		.await
+		.map_err(|e| e.to_string())?;
+	let header = sdk
+		.rpc
+		.chain
+		.get_header(Some(block_hash))
+		.await
+		.map_err(|e| e.to_string())?;
+	let nonce = sdk
+		.rpc
+		.system
+		.account_next_index(account_id.to_string())
+		.await
+		.map_err(|e| e.to_string())?;
+	let metadata = sdk.api.metadata();
+	let params = OfflineParams {
+		genesis_hash: sdk.api.genesis_hash(),
+		spec_version: runtime_version.spec_version,
+		transaction_version: runtime_version.transaction_version,
+		nonce: nonce as u64,
+		mortality: Some(OfflineMortality {
+			period: 32,
+			block_number: header.number,
+			block_hash,
+		}),
+		tip: 0,
+		app_id: 1,
+	};
+
+	// Offline machine: build and sign the transaction without any network access.
+	let secret_uri = SecretUri::from_str("//Alice").unwrap();
+	let account = Keypair::from_uri(&secret_uri).unwrap();
+	let data = Data {
+		0: String::from("My Awesome Data").as_bytes().to_vec(),
+	};
+	let call = avail::tx().data_availability().submit_data(data);
+	let partial = utils_raw::create_partial_signed_offline(&call, metadata, params)?;
+	let signed = partial.sign(&account).into_encoded();
+
+	// Online machine: submit the signed transaction.
+	let maybe_tx_progress = sdk.util.submit_and_watch(signed).await;
+	let tx_in_block = sdk
+		.util
+		.progress_transaction(maybe_tx_progress, WaitFor::BlockInclusion)
+		.await?;
+	println!("BlockHash={:?}", tx_in_block.block_hash());
+
+	Ok(())
+}
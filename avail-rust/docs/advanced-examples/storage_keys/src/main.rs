@@ -0,0 +1,40 @@
+use avail_rust::{avail, storage_key, AccountId, SDK};
+use core::str::FromStr;
+
+type AccountInfo = avail::runtime_types::frame_system::AccountInfo<
+	u32,
+	avail::runtime_types::pallet_balances::types::AccountData<u128>,
+>;
+
+#[tokio::main]
+async fn main() -> Result<(), String> {
+	let sdk = SDK::new("ws://127.0.0.1:9944").await.unwrap();
+
+	// Input
+	let account_id =
+		AccountId::from_str("5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY").unwrap(); // Alice
+
+	// Hashers of `System::Account` are read from the metadata.
+	let metadata = sdk.api.metadata();
+	let key = storage_key::map(&metadata, "System", "Account", &account_id)?;
+	let account = sdk
+		.rpc
+		.state
+		.get_storage_decoded::<AccountInfo>(key, None)
+		.await
+		.map_err(|e| e.to_string())?;
+	if let Some(account) = account {
+		println!("Nonce={}, Free={}", account.nonce, account.data.free);
+	}
+
+	let key = storage_key::plain("System", "Number");
+	let block_number = sdk
+		.rpc
+		.state
+		.get_storage_decoded::<u32>(key, None)
+		.await
+		.map_err(|e| e.to_string())?;
+	println!("BlockNumber={:?}", block_number);
+
+	Ok(())
+}
@@ -0,0 +1,27 @@
+use avail_rust::{
+	subxt::ext::futures::future::join_all, Keypair, Options, SecretUri, SubmissionQueue, SDK,
+};
+use core::str::FromStr;
+
+#[tokio::main]
+async fn main() -> Result<(), String> {
+	let sdk = SDK::new("ws://127.0.0.1:9944").await.unwrap();
+
+	// Input
+	let secret_uri = SecretUri::from_str("//Alice").unwrap();
+	let account = Keypair::from_uri(&secret_uri).unwrap();
+	let queue = SubmissionQueue::new(&sdk, account).options(Options::new().app_id(1));
+
+	// All submissions are in flight at the same time. Nonces are assigned in the order the
+	// submissions are enqueued.
+	let submissions = (0..5).map(|i| queue.enqueue(std::format!("Data {}", i).into_bytes()));
+	for result in join_all(submissions).await {
+		let result = result?;
+		println!(
+			"BlockHash={:?}, TxIndex={}, DataHash={:?}",
+			result.block_hash, result.tx_index, result.event.data_hash
+		);
+	}
+
+	Ok(())
+}
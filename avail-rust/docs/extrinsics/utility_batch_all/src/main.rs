@@ -0,0 +1,36 @@
+use avail_rust::{
+	avail::runtime_types::da_control::pallet::Call as DataAvailabilityCall, avail::Call, Data,
+	Keypair, Nonce, Options, SecretUri, WaitFor, SDK,
+};
+use core::str::FromStr;
+
+#[tokio::main]
+async fn main() -> Result<(), String> {
+	let sdk = SDK::new("ws://127.0.0.1:9944").await.unwrap();
+
+	// Input
+	let secret_uri = SecretUri::from_str("//Alice").unwrap();
+	let account = Keypair::from_uri(&secret_uri).unwrap();
+	let first = Data {
+		0: String::from("First").as_bytes().to_vec(),
+	};
+	let second = Data {
+		0: String::from("Second").as_bytes().to_vec(),
+	};
+	let calls = vec![
+		Call::DataAvailability(DataAvailabilityCall::submit_data { data: first }),
+		Call::DataAvailability(DataAvailabilityCall::submit_data { data: second }),
+	];
+
+	let wait_for = WaitFor::BlockInclusion;
+	let options = Options::new().nonce(Nonce::BestBlockAndTxPool);
+	let result = sdk
+		.tx
+		.utility
+		.batch_all(calls, wait_for, &account, Some(options))
+		.await?;
+
+	dbg!(result);
+
+	Ok(())
+}
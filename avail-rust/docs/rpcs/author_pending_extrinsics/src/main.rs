@@ -0,0 +1,27 @@
+use avail_rust::SDK;
+
+#[tokio::main]
+async fn main() -> Result<(), String> {
+	let sdk = SDK::new("ws://127.0.0.1:9944")
+		.await
+		.map_err(|e| e.to_string())?;
+
+	let extrinsics = sdk
+		.rpc
+		.author
+		.pending_extrinsics()
+		.await
+		.map_err(|e| e.to_string())?;
+	let extrinsics = sdk.util.decode_raw_block_rpc_extrinsics(extrinsics)?;
+	for extrinsic in extrinsics {
+		println!(
+			"Hash={:?}, AppId={:?}, Nonce={:?}, Tip={:?}",
+			extrinsic.hash(),
+			extrinsic.app_id(),
+			extrinsic.nonce(),
+			extrinsic.tip()
+		);
+	}
+
+	Ok(())
+}
@@ -0,0 +1,22 @@
+use avail_rust::{H256, SDK};
+use core::str::FromStr;
+
+#[tokio::main]
+async fn main() -> Result<(), String> {
+	let sdk = SDK::new("ws://127.0.0.1:9944")
+		.await
+		.map_err(|e| e.to_string())?;
+
+	let tx_hash =
+		H256::from_str("0x2b7d7a3b29c4f8e2b3e4b7a0f2d1c6f9e5a3b1c7d9e2f4a6b8c0d2e4f6a8b0c2")
+			.map_err(|e| e.to_string())?;
+	let removed = sdk
+		.rpc
+		.author
+		.remove_extrinsic(vec![tx_hash])
+		.await
+		.map_err(|e| e.to_string())?;
+	println!("Removed={:?}", removed);
+
+	Ok(())
+}
@@ -0,0 +1,27 @@
+use avail_rust::SDK;
+
+#[tokio::main]
+async fn main() -> Result<(), String> {
+	let sdk = SDK::new("ws://127.0.0.1:9944")
+		.await
+		.map_err(|e| e.to_string())?;
+
+	// Heights and hashes of the blocks an indexer has already processed.
+	let header = sdk
+		.rpc
+		.chain
+		.get_header(None)
+		.await
+		.map_err(|e| e.to_string())?;
+	let known = vec![(header.number - 1, header.parent_hash)];
+
+	let reorged = sdk
+		.rpc
+		.chain
+		.detect_reorg(&known)
+		.await
+		.map_err(|e| e.to_string())?;
+	println!("ReorgedFrom={:?}", reorged);
+
+	Ok(())
+}
@@ -0,0 +1,21 @@
+use avail_rust::{subxt::ext::futures::StreamExt, SDK};
+
+#[tokio::main]
+async fn main() -> Result<(), String> {
+	let sdk = SDK::new("ws://127.0.0.1:9944")
+		.await
+		.map_err(|e| e.to_string())?;
+
+	let blocks = sdk.rpc.chain.get_blocks_range(1, 100, 10);
+	let mut blocks = std::pin::pin!(blocks);
+	while let Some(block) = blocks.next().await {
+		let block = block.map_err(|e| e.to_string())?;
+		println!(
+			"BlockNumber={:?}, Extrinsics={}",
+			block.block.header.number,
+			block.block.extrinsics.len()
+		);
+	}
+
+	Ok(())
+}
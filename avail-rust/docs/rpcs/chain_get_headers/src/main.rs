@@ -0,0 +1,28 @@
+use avail_rust::SDK;
+
+#[tokio::main]
+async fn main() -> Result<(), String> {
+	let sdk = SDK::new("ws://127.0.0.1:9944")
+		.await
+		.map_err(|e| e.to_string())?;
+
+	let best = sdk
+		.rpc
+		.chain
+		.get_block_hash(None)
+		.await
+		.map_err(|e| e.to_string())?;
+	let finalized = sdk
+		.rpc
+		.chain
+		.get_finalized_head()
+		.await
+		.map_err(|e| e.to_string())?;
+
+	let headers = sdk.rpc.chain.get_headers(&[best, finalized]).await;
+	for header in headers {
+		println!("BlockHeader={:?}", header.map_err(|e| e.to_string())?);
+	}
+
+	Ok(())
+}
@@ -0,0 +1,18 @@
+use avail_rust::SDK;
+
+#[tokio::main]
+async fn main() -> Result<(), String> {
+	let sdk = SDK::new("ws://127.0.0.1:9944")
+		.await
+		.map_err(|e| e.to_string())?;
+
+	let value = sdk
+		.rpc
+		.chain_spec
+		.chain_name()
+		.await
+		.map_err(|e| e.to_string())?;
+	println!("ChainName={:?}", value);
+
+	Ok(())
+}
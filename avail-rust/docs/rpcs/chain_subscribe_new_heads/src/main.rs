@@ -0,0 +1,24 @@
+use avail_rust::{subxt::ext::futures::StreamExt, SDK};
+
+#[tokio::main]
+async fn main() -> Result<(), String> {
+	let sdk = SDK::new("ws://127.0.0.1:9944")
+		.await
+		.map_err(|e| e.to_string())?;
+
+	let mut heads = sdk
+		.rpc
+		.chain
+		.subscribe_new_heads()
+		.await
+		.map_err(|e| e.to_string())?;
+	for _ in 0..3 {
+		let Some(header) = heads.next().await else {
+			break;
+		};
+		let header = header.map_err(|e| e.to_string())?;
+		println!("BestBlockNumber={:?}", header.number);
+	}
+
+	Ok(())
+}
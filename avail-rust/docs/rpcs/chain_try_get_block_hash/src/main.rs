@@ -0,0 +1,21 @@
+use avail_rust::SDK;
+
+#[tokio::main]
+async fn main() -> Result<(), String> {
+	let sdk = SDK::new("ws://127.0.0.1:9944")
+		.await
+		.map_err(|e| e.to_string())?;
+
+	let block_hash = sdk
+		.rpc
+		.chain
+		.try_get_block_hash(1)
+		.await
+		.map_err(|e| e.to_string())?;
+	match block_hash {
+		Some(block_hash) => println!("BlockHash={:?}", block_hash),
+		None => println!("Block 1 does not exist yet"),
+	}
+
+	Ok(())
+}
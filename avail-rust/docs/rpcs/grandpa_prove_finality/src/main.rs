@@ -0,0 +1,32 @@
+use avail_rust::SDK;
+
+#[tokio::main]
+async fn main() -> Result<(), String> {
+	let sdk = SDK::new("ws://127.0.0.1:9944")
+		.await
+		.map_err(|e| e.to_string())?;
+
+	let header = sdk
+		.rpc
+		.chain
+		.get_header(None)
+		.await
+		.map_err(|e| e.to_string())?;
+	let Some(proof) = sdk
+		.rpc
+		.grandpa
+		.prove_finality(header.number)
+		.await
+		.map_err(|e| e.to_string())?
+	else {
+		println!("Block is not finalized yet");
+		return Ok(());
+	};
+
+	let justification = proof.decode_justification().map_err(|e| e.to_string())?;
+	println!("Block={:?}", proof.block);
+	println!("Round={:?}", justification.round);
+	println!("Precommits={:?}", justification.commit.precommits.len());
+
+	Ok(())
+}
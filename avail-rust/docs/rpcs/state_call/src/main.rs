@@ -0,0 +1,23 @@
+use avail_rust::{subxt::backend::legacy::rpc_methods::Bytes, AccountId, SDK};
+use core::str::FromStr;
+
+#[tokio::main]
+async fn main() -> Result<(), String> {
+	let sdk = SDK::new("ws://127.0.0.1:9944")
+		.await
+		.map_err(|e| e.to_string())?;
+
+	// The SCALE encoding of an account id is its 32 bytes.
+	let account_id =
+		AccountId::from_str("5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY").unwrap(); // Alice
+	let data = Bytes(account_id.0.to_vec());
+	let nonce = sdk
+		.rpc
+		.state
+		.call_decoded::<u32>("AccountNonceApi_account_nonce", data, None)
+		.await
+		.map_err(|e| e.to_string())?;
+	println!("Nonce={}", nonce);
+
+	Ok(())
+}
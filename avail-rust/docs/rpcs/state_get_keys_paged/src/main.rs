@@ -0,0 +1,35 @@
+use avail_rust::{avail, StorageKey, SDK};
+
+#[tokio::main]
+async fn main() -> Result<(), String> {
+	let sdk = SDK::new("ws://127.0.0.1:9944")
+		.await
+		.map_err(|e| e.to_string())?;
+
+	let address = avail::storage().data_availability().app_keys_iter();
+	let prefix = sdk
+		.api
+		.storage()
+		.address_bytes(&address)
+		.map_err(|e| e.to_string())?;
+	let prefix = StorageKey::from(prefix);
+
+	let mut start_key = None;
+	loop {
+		let keys = sdk
+			.rpc
+			.state
+			.get_keys_paged(prefix.clone(), 100, start_key, None)
+			.await
+			.map_err(|e| e.to_string())?;
+		for key in &keys {
+			println!("Key={:?}", key);
+		}
+		if keys.len() < 100 {
+			break;
+		}
+		start_key = keys.last().cloned();
+	}
+
+	Ok(())
+}
@@ -0,0 +1,24 @@
+use avail_rust::{avail, StorageKey, SDK};
+
+#[tokio::main]
+async fn main() -> Result<(), String> {
+	let sdk = SDK::new("ws://127.0.0.1:9944")
+		.await
+		.map_err(|e| e.to_string())?;
+
+	let address = avail::storage().data_availability().next_app_id();
+	let key = sdk
+		.api
+		.storage()
+		.address_bytes(&address)
+		.map_err(|e| e.to_string())?;
+	let value = sdk
+		.rpc
+		.state
+		.get_storage(StorageKey::from(key), None)
+		.await
+		.map_err(|e| e.to_string())?;
+	println!("Value={:?}", value);
+
+	Ok(())
+}
@@ -0,0 +1,37 @@
+use avail_rust::{avail, AccountId, StorageKey, SDK};
+use core::str::FromStr;
+
+#[tokio::main]
+async fn main() -> Result<(), String> {
+	let sdk = SDK::new("ws://127.0.0.1:9944")
+		.await
+		.map_err(|e| e.to_string())?;
+
+	let accounts = [
+		"5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY", // Alice
+		"5FHneW46xGXgs5mUiveU4sbTyGBzmstUspZC92UhjJM694ty", // Bob
+	];
+	let mut keys = Vec::new();
+	for account in accounts {
+		let account_id = AccountId::from_str(account).map_err(|e| e.to_string())?;
+		let address = avail::storage().system().account(account_id);
+		let key = sdk
+			.api
+			.storage()
+			.address_bytes(&address)
+			.map_err(|e| e.to_string())?;
+		keys.push(StorageKey::from(key));
+	}
+
+	let values = sdk
+		.rpc
+		.state
+		.query_storage_at(keys, None)
+		.await
+		.map_err(|e| e.to_string())?;
+	for (key, value) in values {
+		println!("Key={:?}, Value={:?}", key, value);
+	}
+
+	Ok(())
+}
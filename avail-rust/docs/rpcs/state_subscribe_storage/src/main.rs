@@ -0,0 +1,30 @@
+use avail_rust::{avail, subxt::ext::futures::StreamExt, StorageKey, SDK};
+
+#[tokio::main]
+async fn main() -> Result<(), String> {
+	let sdk = SDK::new("ws://127.0.0.1:9944")
+		.await
+		.map_err(|e| e.to_string())?;
+
+	let address = avail::storage().data_availability().next_app_id();
+	let key = sdk
+		.api
+		.storage()
+		.address_bytes(&address)
+		.map_err(|e| e.to_string())?;
+	let mut changes = sdk
+		.rpc
+		.state
+		.subscribe_storage(vec![StorageKey::from(key)])
+		.await
+		.map_err(|e| e.to_string())?;
+	for _ in 0..3 {
+		let Some(change) = changes.next().await else {
+			break;
+		};
+		let (block_hash, values) = change.map_err(|e| e.to_string())?;
+		println!("BlockHash={:?}, Changes={:?}", block_hash, values);
+	}
+
+	Ok(())
+}
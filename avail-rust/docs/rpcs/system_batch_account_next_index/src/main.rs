@@ -0,0 +1,19 @@
+use avail_rust::SDK;
+
+#[tokio::main]
+async fn main() -> Result<(), String> {
+	let sdk = SDK::new("ws://127.0.0.1:9944")
+		.await
+		.map_err(|e| e.to_string())?;
+
+	let accounts = vec![
+		String::from("5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY"), // Alice
+		String::from("5FHneW46xGXgs5mUiveU4sbTyGBzmstUspZC92UhjJM694ty"), // Bob
+	];
+	let nonces = sdk.rpc.system.batch_account_next_index(accounts).await;
+	for nonce in nonces {
+		println!("Nonce={:?}", nonce.map_err(|e| e.to_string())?);
+	}
+
+	Ok(())
+}
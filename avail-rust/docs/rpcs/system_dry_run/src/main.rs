@@ -0,0 +1,37 @@
+use avail_rust::{
+	avail, subxt::backend::legacy::rpc_methods::Bytes, AvailExtrinsicParamsBuilder, Data, Keypair,
+	SecretUri, SDK,
+};
+use core::str::FromStr;
+
+#[tokio::main]
+async fn main() -> Result<(), String> {
+	let sdk = SDK::new("ws://127.0.0.1:9944")
+		.await
+		.map_err(|e| e.to_string())?;
+
+	// Input
+	let secret_uri = SecretUri::from_str("//Alice").unwrap();
+	let account = Keypair::from_uri(&secret_uri).unwrap();
+	let data = Data {
+		0: String::from("My Awesome Data").as_bytes().to_vec(),
+	};
+
+	let call = avail::tx().data_availability().submit_data(data);
+	let params = AvailExtrinsicParamsBuilder::new().build();
+	let tx = sdk
+		.api
+		.tx()
+		.create_signed(&call, &account, params)
+		.await
+		.map_err(|e| e.to_string())?;
+	let result = sdk
+		.rpc
+		.system
+		.dry_run(Bytes::from(tx.into_encoded()), None)
+		.await
+		.map_err(|e| e.to_string())?;
+	println!("Result={:?}", result);
+
+	Ok(())
+}
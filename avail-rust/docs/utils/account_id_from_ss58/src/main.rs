@@ -0,0 +1,13 @@
+use avail_rust::SDK;
+
+#[tokio::main]
+async fn main() -> Result<(), String> {
+	let sdk = SDK::new("ws://127.0.0.1:9944").await.unwrap();
+
+	let address = "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY";
+	let (account_id, prefix) = sdk.util.account_id_from_ss58(address)?;
+
+	println!("Account Id: {}, Prefix: {}", account_id, prefix);
+
+	Ok(())
+}
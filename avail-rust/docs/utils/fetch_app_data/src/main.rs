@@ -0,0 +1,33 @@
+use avail_rust::{Data, Keypair, Options, SecretUri, WaitFor, SDK};
+use core::str::FromStr;
+
+#[tokio::main]
+async fn main() -> Result<(), String> {
+	let sdk = SDK::new("ws://127.0.0.1:9944").await.unwrap();
+
+	// Input
+	let secret_uri = SecretUri::from_str("//Alice").unwrap();
+	let account = Keypair::from_uri(&secret_uri).unwrap();
+	let data = Data {
+		0: String::from("My Awesome Data").as_bytes().to_vec(),
+	};
+	let app_id = 1;
+
+	let options = Options::new().app_id(app_id);
+	let result = sdk
+		.tx
+		.data_availability
+		.submit_data(data, WaitFor::BlockInclusion, &account, Some(options))
+		.await?;
+
+	let app_data = sdk
+		.util
+		.fetch_app_data(result.block_hash, app_id)
+		.await
+		.map_err(|e| e.to_string())?;
+	for data in app_data {
+		println!("Data={:?}", String::from_utf8_lossy(&data.0));
+	}
+
+	Ok(())
+}
@@ -0,0 +1,18 @@
+use avail_rust::{AccountId, SDK};
+use core::str::FromStr;
+
+#[tokio::main]
+async fn main() -> Result<(), String> {
+	let sdk = SDK::new("ws://127.0.0.1:9944").await.unwrap();
+
+	let account_id =
+		AccountId::from_str("5GNJqTPyNqANBkUVMN1LPPrxXnFouWXoe2wNSmmEoLctxiZY").unwrap(); // Alice_Stash
+	let balance = sdk.util.fetch_balance(&account_id, None).await?;
+
+	println!(
+		"Free={}, Reserved={}, Frozen={}, Nonce={}",
+		balance.free, balance.reserved, balance.frozen, balance.nonce
+	);
+
+	Ok(())
+}
@@ -0,0 +1,23 @@
+use avail_rust::{avail, SDK};
+
+use avail::data_availability::events as DataAvailabilityEvents;
+
+#[tokio::main]
+async fn main() -> Result<(), String> {
+	let sdk = SDK::new("ws://127.0.0.1:9944").await.unwrap();
+
+	let block_hash = sdk
+		.rpc
+		.chain
+		.get_finalized_head()
+		.await
+		.map_err(|e| e.to_string())?;
+	let events = sdk.util.fetch_events(block_hash).await?;
+
+	for event in events.find::<DataAvailabilityEvents::DataSubmitted>() {
+		let event = event.map_err(|e| e.to_string())?;
+		println!("DataSubmitted={:?}", event);
+	}
+
+	Ok(())
+}
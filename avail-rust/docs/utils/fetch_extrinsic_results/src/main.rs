@@ -0,0 +1,25 @@
+use avail_rust::SDK;
+
+#[tokio::main]
+async fn main() -> Result<(), String> {
+	let sdk = SDK::new("ws://127.0.0.1:9944").await.unwrap();
+
+	let block_hash = sdk
+		.rpc
+		.chain
+		.get_finalized_head()
+		.await
+		.map_err(|e| e.to_string())?;
+	let results = sdk.util.fetch_extrinsic_results(block_hash).await?;
+
+	for (index, result) in results {
+		println!(
+			"Index={}, Success={}, Weight={:?}",
+			index,
+			result.is_success(),
+			result.weight()
+		);
+	}
+
+	Ok(())
+}
@@ -0,0 +1,16 @@
+use avail_rust::{AppUncheckedExtrinsic, SDK};
+
+#[tokio::main]
+async fn main() -> Result<(), String> {
+	let sdk = SDK::new("ws://127.0.0.1:9944").await.unwrap();
+
+	// Signed extrinsic together with the number of the block its mortality was anchored to.
+	let extrinsic = "0x...";
+	let checkpoint = 100;
+
+	let extrinsic = AppUncheckedExtrinsic::from_hex(extrinsic)?;
+	let live = sdk.util.is_extrinsic_live(&extrinsic, checkpoint).await?;
+	println!("Live={}", live);
+
+	Ok(())
+}
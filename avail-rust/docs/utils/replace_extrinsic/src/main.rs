@@ -0,0 +1,32 @@
+use avail_rust::{AppUncheckedExtrinsic, Keypair, SecretUri, WaitFor, SDK};
+use core::str::FromStr;
+
+#[tokio::main]
+async fn main() -> Result<(), String> {
+	let sdk = SDK::new("ws://127.0.0.1:9944").await.unwrap();
+
+	// Input
+	let secret_uri = SecretUri::from_str("//Alice").unwrap();
+	let account = Keypair::from_uri(&secret_uri).unwrap();
+
+	// Take the first pending transaction and pay a higher tip for it.
+	let pending = sdk
+		.rpc
+		.author
+		.pending_extrinsics()
+		.await
+		.map_err(|e| e.to_string())?;
+	let Some(original) = pending.first() else {
+		return Err(String::from("No pending transactions"));
+	};
+	let original = AppUncheckedExtrinsic::try_from(original.clone())?;
+	let tip = original.tip().unwrap_or_default() + 1_000_000_000_000_000;
+
+	let tx_in_block = sdk
+		.util
+		.replace_extrinsic(&original, tip, WaitFor::BlockInclusion, &account)
+		.await?;
+	println!("BlockHash={:?}", tx_in_block.block_hash());
+
+	Ok(())
+}
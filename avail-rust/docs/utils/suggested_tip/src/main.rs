@@ -0,0 +1,27 @@
+use avail_rust::{Data, Keypair, Options, SecretUri, WaitFor, SDK};
+use core::str::FromStr;
+
+#[tokio::main]
+async fn main() -> Result<(), String> {
+	let sdk = SDK::new("ws://127.0.0.1:9944").await.unwrap();
+
+	// Input
+	let secret_uri = SecretUri::from_str("//Alice").unwrap();
+	let account = Keypair::from_uri(&secret_uri).unwrap();
+	let data = Data {
+		0: String::from("My Awesome Data").as_bytes().to_vec(),
+	};
+
+	let tip = sdk.util.suggested_tip(75).await?;
+	println!("Tip={:?}", tip);
+
+	let options = Options::new().tip(tip);
+	let result = sdk
+		.tx
+		.data_availability
+		.submit_data(data, WaitFor::BlockInclusion, &account, Some(options))
+		.await?;
+	println!("BlockHash={:?}", result.block_hash);
+
+	Ok(())
+}
@@ -0,0 +1,14 @@
+use avail_rust::SDK;
+
+#[tokio::main]
+async fn main() -> Result<(), String> {
+	let sdk = SDK::new("ws://127.0.0.1:9944").await.unwrap();
+
+	let app_id = 1;
+	match sdk.util.validate_app_id(app_id).await {
+		Ok(()) => println!("Application id {} is registered", app_id),
+		Err(reason) => println!("{}", reason),
+	}
+
+	Ok(())
+}
@@ -0,0 +1,32 @@
+use avail_rust::{avail, AvailExtrinsicParamsBuilder, Data, Keypair, SecretUri, SDK};
+use core::str::FromStr;
+use std::time::Duration;
+
+#[tokio::main]
+async fn main() -> Result<(), String> {
+	let sdk = SDK::new("ws://127.0.0.1:9944").await.unwrap();
+
+	// Input
+	let secret_uri = SecretUri::from_str("//Alice").unwrap();
+	let account = Keypair::from_uri(&secret_uri).unwrap();
+	let data = Data {
+		0: String::from("My Awesome Data").as_bytes().to_vec(),
+	};
+
+	let call = avail::tx().data_availability().submit_data(data);
+	let params = AvailExtrinsicParamsBuilder::new().build();
+	let tx_hash = sdk
+		.api
+		.tx()
+		.sign_and_submit(&call, &account, params)
+		.await
+		.map_err(|e| e.to_string())?;
+
+	let (block_hash, tx_index) = sdk
+		.util
+		.wait_for_inclusion(tx_hash, Duration::from_secs(120))
+		.await?;
+	println!("BlockHash={:?}, TxIndex={:?}", block_hash, tx_index);
+
+	Ok(())
+}
@@ -0,0 +1,82 @@
+use crate::avail::runtime_types::bounded_collections::bounded_vec::BoundedVec;
+use crate::rpcs::Rpc;
+use crate::transactions::{DataAvailability, Nonce, Options, SubmitDataTxSuccess};
+use crate::{AvailConfig, WaitFor, SDK};
+use futures::lock::Mutex;
+use std::sync::Arc;
+use subxt::tx::Signer;
+
+/// Submits data for a single application, always signed by the same account.
+///
+/// Every submission waits for finalization. Nonces are assigned locally: submissions are
+/// signed and handed to the node one at a time, so several of them can be in flight at the
+/// same time. The first submission, and the one after a failure, fetches the nonce from the
+/// node. Clones share the same nonce counter.
+#[derive(Clone)]
+pub struct AppClient<S> {
+	data_availability: DataAvailability,
+	rpc: Rpc,
+	app_id: u32,
+	signer: S,
+	next_nonce: Arc<Mutex<Option<u32>>>,
+}
+
+impl<S: Signer<AvailConfig>> AppClient<S> {
+	pub fn new(sdk: &SDK, app_id: u32, signer: S) -> Self {
+		Self {
+			data_availability: sdk.tx.data_availability.clone(),
+			rpc: sdk.rpc.clone(),
+			app_id,
+			signer,
+			next_nonce: Arc::new(Mutex::new(None)),
+		}
+	}
+
+	pub fn app_id(&self) -> u32 {
+		self.app_id
+	}
+
+	pub async fn submit(&self, data: Vec<u8>) -> Result<SubmitDataTxSuccess, String> {
+		let mut next_nonce = self.next_nonce.lock().await;
+
+		let nonce = match *next_nonce {
+			Some(nonce) => nonce,
+			None => {
+				let account_id = self.signer.account_id();
+				self.rpc
+					.system
+					.account_next_index(account_id.to_string())
+					.await
+					.map_err(|e| e.to_string())?
+			},
+		};
+
+		let options = Options::new()
+			.app_id(self.app_id)
+			.nonce(Nonce::Custom(nonce));
+		let tx = self
+			.data_availability
+			.create_submit_data(BoundedVec(data), &self.signer, Some(options))
+			.await?;
+
+		let tx_progress = match tx.submit_and_watch().await {
+			Ok(tx_progress) => tx_progress,
+			Err(error) => {
+				*next_nonce = None;
+				return Err(error.to_string());
+			},
+		};
+
+		*next_nonce = Some(nonce + 1);
+		drop(next_nonce);
+
+		let result = self
+			.data_availability
+			.watch_submit_data(Ok(tx_progress), WaitFor::BlockFinalization, self.app_id)
+			.await;
+		if result.is_err() {
+			*self.next_nonce.lock().await = None;
+		}
+		result
+	}
+}
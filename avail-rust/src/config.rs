@@ -1,8 +1,9 @@
 use crate::{AvailHeader, DefaultExtrinsicParams, DefaultExtrinsicParamsBuilder};
 use subxt::{
-	backend::legacy::rpc_methods::{Block as BlockRPC, BlockDetails as BlockDetailsRPC},
+	backend::legacy::rpc_methods::{Block as BlockRPC, BlockDetails as BlockDetailsRPC, Bytes},
 	blocks::BlocksClient,
 	config::substrate::BlakeTwo256,
+	constants::ConstantsClient,
 	tx::{TxClient, TxInBlock},
 	utils::{AccountId32, MultiAddress, MultiSignature, H256},
 	Config, OnlineClient,
@@ -15,10 +16,15 @@ pub type Address = MultiAddress<AccountId, AccountIndex>;
 pub type Signature = MultiSignature;
 pub type BlockNumber = u32;
 pub type BlockHash = H256;
+/// Hash of an extrinsic, as returned by `author_submitExtrinsic`. Not to be confused with
+/// [`BlockHash`].
+pub type TxHash = H256;
+pub type StorageKey = Bytes;
 
 /// Clients
 pub type Api = OnlineClient<AvailConfig>;
 pub type AvailBlocksClient = BlocksClient<AvailConfig, Api>;
+pub type AvailConstantsClient = ConstantsClient<AvailConfig, Api>;
 pub type TxApi = TxClient<AvailConfig, Api>;
 
 /// TX status
@@ -0,0 +1,42 @@
+use core::fmt::{Display, Formatter, Result as FmtResult};
+
+/// Error returned by the SDK RPC methods.
+#[derive(Debug)]
+pub enum ClientError {
+	/// The request could not be sent or the node responded with an error.
+	Rpc(subxt::Error),
+	/// The node returned a value that is not valid hex.
+	Hex(hex::FromHexError),
+	/// The node returned bytes that could not be SCALE decoded.
+	Codec(codec::Error),
+}
+
+impl Display for ClientError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		match self {
+			ClientError::Rpc(error) => write!(f, "RPC error: {}", error),
+			ClientError::Hex(error) => write!(f, "Hex decode error: {}", error),
+			ClientError::Codec(error) => write!(f, "SCALE decode error: {}", error),
+		}
+	}
+}
+
+impl std::error::Error for ClientError {}
+
+impl From<subxt::Error> for ClientError {
+	fn from(value: subxt::Error) -> Self {
+		Self::Rpc(value)
+	}
+}
+
+impl From<hex::FromHexError> for ClientError {
+	fn from(value: hex::FromHexError) -> Self {
+		Self::Hex(value)
+	}
+}
+
+impl From<codec::Error> for ClientError {
+	fn from(value: codec::Error) -> Self {
+		Self::Codec(value)
+	}
+}
@@ -0,0 +1,220 @@
+use crate::retry::{is_transient, NO_RETRY_METHODS};
+use jsonrpsee::core::client::Error as JsonRpseeError;
+use serde_json::value::RawValue;
+use std::sync::atomic::{AtomicU32, Ordering};
+use subxt::{
+	backend::rpc::{RawRpcFuture, RawRpcSubscription, RpcClientT},
+	error::RpcError,
+};
+
+#[derive(Debug, Clone)]
+pub struct FailoverPolicy {
+	/// Methods that are moved to the next endpoint only if the client refused the request
+	/// before writing it to the socket. Any other error, including a closed connection, is
+	/// returned as is, since jsonrpsee reports a closed connection for requests that were
+	/// already written as well. By default this covers transaction submission.
+	pub no_failover_methods: Vec<String>,
+}
+
+impl FailoverPolicy {
+	pub fn new() -> Self {
+		Self {
+			no_failover_methods: NO_RETRY_METHODS.iter().map(|m| m.to_string()).collect(),
+		}
+	}
+
+	/// Moves `method` to the next endpoint only if the request provably was never sent.
+	pub fn no_failover(mut self, method: &str) -> Self {
+		self.no_failover_methods.push(method.to_string());
+		self
+	}
+
+	fn can_failover(&self, method: &str) -> bool {
+		!self.no_failover_methods.iter().any(|m| m == method)
+	}
+}
+
+impl Default for FailoverPolicy {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+/// RPC client spreading requests over several endpoints. A request failing with a transport
+/// error is sent to the next endpoint. Endpoints are tried in order of their consecutive
+/// failures, so an endpoint that keeps failing is only used once all others fail as well.
+pub struct FailoverClient<C> {
+	clients: Vec<C>,
+	failures: Vec<AtomicU32>,
+	policy: FailoverPolicy,
+}
+
+impl<C: RpcClientT> FailoverClient<C> {
+	pub fn new(clients: Vec<C>, policy: FailoverPolicy) -> Self {
+		let failures = clients.iter().map(|_| AtomicU32::new(0)).collect();
+		Self {
+			clients,
+			failures,
+			policy,
+		}
+	}
+
+	/// Endpoint indices, least failing first. Ties keep the order the endpoints were given in.
+	fn order(&self) -> Vec<usize> {
+		let mut order: Vec<usize> = (0..self.clients.len()).collect();
+		order.sort_by_key(|&i| self.failures[i].load(Ordering::Relaxed));
+		order
+	}
+
+	fn record(&self, index: usize, success: bool) {
+		if success {
+			self.failures[index].store(0, Ordering::Relaxed);
+		} else {
+			self.failures[index].fetch_add(1, Ordering::Relaxed);
+		}
+	}
+}
+
+impl<C: RpcClientT> RpcClientT for FailoverClient<C> {
+	fn request_raw<'a>(
+		&'a self,
+		method: &'a str,
+		params: Option<Box<RawValue>>,
+	) -> RawRpcFuture<'a, Box<RawValue>> {
+		Box::pin(async move {
+			let can_failover = self.policy.can_failover(method);
+
+			let mut last_error = None;
+			for index in self.order() {
+				let result = self.clients[index]
+					.request_raw(method, params.clone())
+					.await;
+				let error = match result {
+					Ok(value) => {
+						self.record(index, true);
+						return Ok(value);
+					},
+					Err(error) => error,
+				};
+
+				let failover = match can_failover {
+					true => is_transient(&error),
+					false => is_not_sent(&error),
+				};
+				if !failover {
+					return Err(error);
+				}
+				self.record(index, false);
+				last_error = Some(error);
+			}
+
+			Err(last_error.unwrap_or_else(no_endpoints))
+		})
+	}
+
+	fn subscribe_raw<'a>(
+		&'a self,
+		sub: &'a str,
+		params: Option<Box<RawValue>>,
+		unsub: &'a str,
+	) -> RawRpcFuture<'a, RawRpcSubscription> {
+		Box::pin(async move {
+			let mut last_error = None;
+			for index in self.order() {
+				let result = self.clients[index]
+					.subscribe_raw(sub, params.clone(), unsub)
+					.await;
+				match result {
+					Ok(subscription) => {
+						self.record(index, true);
+						return Ok(subscription);
+					},
+					Err(error) if is_transient(&error) => {
+						self.record(index, false);
+						last_error = Some(error);
+					},
+					Err(error) => return Err(error),
+				}
+			}
+
+			Err(last_error.unwrap_or_else(no_endpoints))
+		})
+	}
+}
+
+/// The client ran out of request slots, so the request was never written to the socket.
+///
+/// `RestartNeeded` is not enough: jsonrpsee also returns it for requests that were written
+/// before the connection closed, which may have reached the node.
+fn is_not_sent(error: &RpcError) -> bool {
+	let RpcError::ClientError(error) = error else {
+		return false;
+	};
+
+	matches!(
+		error.downcast_ref::<JsonRpseeError>(),
+		Some(JsonRpseeError::MaxSlotsExceeded)
+	)
+}
+
+fn no_endpoints() -> RpcError {
+	RpcError::RequestRejected(String::from("No endpoints available"))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_client::TestClient;
+	use std::sync::Arc;
+
+	fn endpoint(fails: bool) -> TestClient {
+		match fails {
+			true => TestClient::new().failing(u32::MAX),
+			false => TestClient::new(),
+		}
+	}
+
+	fn calls(client: &FailoverClient<TestClient>) -> Vec<u32> {
+		client.clients.iter().map(|c| c.calls()).collect()
+	}
+
+	#[tokio::test]
+	async fn fails_over_to_next_endpoint() {
+		let endpoints = vec![endpoint(true), endpoint(false)];
+		let client = FailoverClient::new(endpoints, FailoverPolicy::new());
+		let result = client.request_raw("chain_getHeader", None).await;
+
+		assert!(result.is_ok());
+		assert_eq!(calls(&client), vec![1, 1]);
+	}
+
+	#[tokio::test]
+	async fn deprioritizes_failing_endpoint() {
+		let endpoints = vec![endpoint(true), endpoint(false)];
+		let client = FailoverClient::new(endpoints, FailoverPolicy::new());
+		let _ = client.request_raw("chain_getHeader", None).await;
+		let result = client.request_raw("chain_getHeader", None).await;
+
+		assert!(result.is_ok());
+		assert_eq!(calls(&client), vec![1, 2]);
+	}
+
+	#[tokio::test]
+	async fn does_not_fail_over_sent_submission() {
+		let endpoints = vec![endpoint(true), endpoint(false)];
+		let client = FailoverClient::new(endpoints, FailoverPolicy::new());
+		let result = client.request_raw("author_submitExtrinsic", None).await;
+
+		assert!(result.is_err());
+		assert_eq!(calls(&client), vec![1, 0]);
+	}
+
+	#[test]
+	fn closed_connection_is_not_proof_of_unsent_request() {
+		let closed = JsonRpseeError::RestartNeeded(Arc::new(JsonRpseeError::RequestTimeout));
+		let no_slots = JsonRpseeError::MaxSlotsExceeded;
+
+		assert!(!is_not_sent(&RpcError::ClientError(Box::new(closed))));
+		assert!(is_not_sent(&RpcError::ClientError(Box::new(no_slots))));
+	}
+}
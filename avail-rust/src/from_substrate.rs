@@ -1,5 +1,9 @@
-use crate::{BlockHash, BlockNumber};
-use serde::{Deserialize, Deserializer};
+use crate::avail::runtime_types::sp_runtime::DispatchError;
+use crate::avail::runtime_types::sp_weights::weight_v2::Weight as ApiWeight;
+use crate::{BlockHash, BlockNumber, StorageKey, TxHash};
+use codec::Decode;
+use serde::{Deserialize, Deserializer, Serialize};
+use subxt::backend::legacy::rpc_methods::Bytes;
 
 /// Network Peer information
 #[derive(Clone, Debug, PartialEq, Deserialize)]
@@ -15,6 +19,27 @@ pub struct PeerInfo {
 	pub best_number: BlockNumber,
 }
 
+/// Storage change set
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct StorageChangeSet {
+	/// Block hash
+	pub block: BlockHash,
+	/// A list of changes
+	pub changes: Vec<(StorageKey, Option<Bytes>)>,
+}
+
+/// RPC Extrinsic or hash
+///
+/// Allows to refer to extrinsic either by its raw representation or its hash.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ExtrinsicOrHash {
+	/// The hash of the extrinsic.
+	Hash(TxHash),
+	/// Raw extrinsic bytes.
+	Extrinsic(Bytes),
+}
+
 /// The role the node is running as
 #[derive(Clone, Debug, PartialEq, Deserialize)]
 pub enum NodeRole {
@@ -106,10 +131,12 @@ where
 {
 	let buf = String::deserialize(deserializer)?;
 	let without_prefix = buf.trim_start_matches("0x");
-	Ok(u128::from_str_radix(without_prefix, 16).unwrap())
+	u128::from_str_radix(without_prefix, 16).map_err(serde::de::Error::custom)
 }
 
-#[derive(Clone, Debug, PartialEq, Deserialize)]
+/// Result of `payment_queryInfo`. It is also the SCALE encoded result of the
+/// `TransactionPaymentApi_query_info` runtime API.
+#[derive(Clone, Debug, PartialEq, Deserialize, Decode)]
 #[serde(rename_all = "camelCase")]
 pub struct RuntimeDispatchInfo {
 	/// Weight of this dispatch.
@@ -124,7 +151,7 @@ pub struct RuntimeDispatchInfo {
 	pub partial_fee: u128,
 }
 
-#[derive(Clone, Debug, PartialEq, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Deserialize, Decode)]
 #[serde(rename_all = "camelCase")]
 pub enum DispatchClass {
 	/// A normal dispatch.
@@ -147,12 +174,93 @@ pub enum DispatchClass {
 	Mandatory,
 }
 
-#[derive(Clone, Debug, PartialEq, Deserialize)]
+/// Two dimensional weight, as used by the runtime since weights v2.
+///
+/// Ordering compares `ref_time` first and `proof_size` second. Use [`Weight::all_lte`] or
+/// [`Weight::any_gt`] to check a weight against a limit.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Deserialize, Decode)]
 pub struct Weight {
 	/// The weight of computational time used based on some reference hardware.
-	ref_time: u64,
+	#[codec(compact)]
+	pub ref_time: u64,
 	/// The weight of storage space used by proof of validity.
-	proof_size: u64,
+	#[codec(compact)]
+	pub proof_size: u64,
+}
+
+impl Weight {
+	pub const fn new(ref_time: u64, proof_size: u64) -> Self {
+		Self {
+			ref_time,
+			proof_size,
+		}
+	}
+
+	/// `true` if both components are less than or equal to the ones of `other`.
+	pub fn all_lte(&self, other: &Self) -> bool {
+		self.ref_time <= other.ref_time && self.proof_size <= other.proof_size
+	}
+
+	/// `true` if any component is greater than the one of `other`.
+	pub fn any_gt(&self, other: &Self) -> bool {
+		!self.all_lte(other)
+	}
+
+	pub fn checked_add(&self, other: &Self) -> Option<Self> {
+		Some(Self {
+			ref_time: self.ref_time.checked_add(other.ref_time)?,
+			proof_size: self.proof_size.checked_add(other.proof_size)?,
+		})
+	}
+
+	pub fn checked_sub(&self, other: &Self) -> Option<Self> {
+		Some(Self {
+			ref_time: self.ref_time.checked_sub(other.ref_time)?,
+			proof_size: self.proof_size.checked_sub(other.proof_size)?,
+		})
+	}
+
+	pub fn saturating_add(&self, other: &Self) -> Self {
+		Self {
+			ref_time: self.ref_time.saturating_add(other.ref_time),
+			proof_size: self.proof_size.saturating_add(other.proof_size),
+		}
+	}
+
+	pub fn saturating_sub(&self, other: &Self) -> Self {
+		Self {
+			ref_time: self.ref_time.saturating_sub(other.ref_time),
+			proof_size: self.proof_size.saturating_sub(other.proof_size),
+		}
+	}
+}
+
+impl core::ops::Add for Weight {
+	type Output = Self;
+
+	fn add(self, rhs: Self) -> Self {
+		Self {
+			ref_time: self.ref_time + rhs.ref_time,
+			proof_size: self.proof_size + rhs.proof_size,
+		}
+	}
+}
+
+impl core::ops::Sub for Weight {
+	type Output = Self;
+
+	fn sub(self, rhs: Self) -> Self {
+		Self {
+			ref_time: self.ref_time - rhs.ref_time,
+			proof_size: self.proof_size - rhs.proof_size,
+		}
+	}
+}
+
+impl From<ApiWeight> for Weight {
+	fn from(weight: ApiWeight) -> Self {
+		Self::new(weight.ref_time, weight.proof_size)
+	}
 }
 
 fn number_from_hex_2<'de, D>(deserializer: D) -> Result<u128, D::Error>
@@ -160,5 +268,101 @@ where
 	D: Deserializer<'de>,
 {
 	let buf = String::deserialize(deserializer)?;
-	Ok(u128::from_str_radix(&buf, 10).unwrap())
+	u128::from_str_radix(&buf, 10).map_err(serde::de::Error::custom)
+}
+
+/// Result of dispatching an extrinsic that passed validation.
+pub type DispatchOutcome = Result<(), DispatchError>;
+
+/// Result returned by the runtime when an extrinsic is applied.
+///
+/// The outer error means the extrinsic is not valid and would not be included in a block. The
+/// inner error means it would be included, and fees paid, but its dispatch failed.
+pub type ApplyExtrinsicResult = Result<DispatchOutcome, TransactionValidityError>;
+
+/// Errors that can occur while checking the validity of a transaction.
+#[derive(Clone, Debug, PartialEq, Eq, Decode)]
+pub enum TransactionValidityError {
+	/// The transaction is invalid.
+	Invalid(InvalidTransaction),
+	/// Transaction validity can't be determined.
+	Unknown(UnknownTransaction),
+}
+
+/// An invalid transaction validity.
+#[derive(Clone, Debug, PartialEq, Eq, Decode)]
+pub enum InvalidTransaction {
+	/// The call of the transaction is not expected.
+	Call,
+	/// General error to do with the inability to pay some fees (e.g. account balance too low).
+	Payment,
+	/// General error to do with the transaction not yet being valid (e.g. nonce too high).
+	Future,
+	/// General error to do with the transaction being outdated (e.g. nonce too low).
+	Stale,
+	/// General error to do with the transaction's proofs (e.g. signature).
+	BadProof,
+	/// The transaction birth block is ancient.
+	AncientBirthBlock,
+	/// The transaction would exhaust the resources of current block.
+	ExhaustsResources,
+	/// Any other custom invalid validity that is not covered by this enum.
+	Custom(u8),
+	/// An extrinsic with a Mandatory dispatch resulted in Error.
+	BadMandatory,
+	/// An extrinsic with a mandatory dispatch tried to be validated.
+	MandatoryValidation,
+	/// The sending address is disabled or known to be invalid.
+	BadSigner,
+}
+
+/// An unknown transaction validity.
+#[derive(Clone, Debug, PartialEq, Eq, Decode)]
+pub enum UnknownTransaction {
+	/// Could not lookup some information that is required to validate the transaction.
+	CannotLookup,
+	/// No validator found for the given unsigned transaction.
+	NoUnsignedValidator,
+	/// Any other custom unknown validity that is not covered by this enum.
+	Custom(u8),
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn runtime_dispatch_info_decodes_v2_weight() {
+		let encoded = hex::decode("02093d00253800000026d05789b8010000000000000000").unwrap();
+		let info = RuntimeDispatchInfo::decode(&mut encoded.as_slice()).unwrap();
+
+		assert_eq!(info.weight, Weight::new(1_000_000, 3593));
+		assert_eq!(info.class, DispatchClass::Normal);
+		assert_eq!(info.partial_fee, 124_000_000_000_000_000);
+	}
+
+	#[test]
+	fn runtime_dispatch_info_deserializes_rpc_json() {
+		let json = r#"{"weight":{"ref_time":1000000,"proof_size":3593},"class":"normal","partialFee":"124000000000000000"}"#;
+		let info: RuntimeDispatchInfo = serde_json::from_str(json).unwrap();
+
+		assert_eq!(info.weight, Weight::new(1_000_000, 3593));
+		assert_eq!(info.class, DispatchClass::Normal);
+		assert_eq!(info.partial_fee, 124_000_000_000_000_000);
+	}
+
+	#[test]
+	fn weight_limits_check_both_components() {
+		let limit = Weight::new(100, 100);
+
+		assert!(Weight::new(100, 100).all_lte(&limit));
+		assert!(Weight::new(10, 101).any_gt(&limit));
+		assert!(Weight::new(101, 10).any_gt(&limit));
+		assert_eq!(Weight::new(1, 2) + Weight::new(3, 4), Weight::new(4, 6));
+		assert_eq!(Weight::new(1, 2).checked_sub(&Weight::new(2, 1)), None);
+		assert_eq!(
+			Weight::new(u64::MAX, 1).saturating_add(&Weight::new(1, 1)),
+			Weight::new(u64::MAX, 2)
+		);
+	}
 }
@@ -1,12 +1,36 @@
+#![forbid(unsafe_code)]
+
 mod api_dev;
+mod app_client;
 mod config;
+mod error;
+#[cfg(not(target_arch = "wasm32"))]
+mod failover;
 mod from_substrate;
+#[cfg(not(target_arch = "wasm32"))]
+mod limit;
+#[cfg(not(target_arch = "wasm32"))]
+mod metadata_cache;
+#[cfg(any(test, feature = "testing"))]
+pub mod mock;
+#[cfg(not(target_arch = "wasm32"))]
+mod reconnect;
+#[cfg(not(target_arch = "wasm32"))]
+mod retry;
 mod rpcs;
 mod sdk;
+#[cfg(not(target_arch = "wasm32"))]
+mod submission_queue;
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod test_client;
+#[cfg(all(feature = "tracing", not(target_arch = "wasm32")))]
+mod traced;
 mod utils;
 
 // Export types for internal and external consumption
+pub mod metadata_hash;
 pub mod primitives;
+pub mod storage_key;
 pub mod transactions;
 
 pub type RewardDestination =
@@ -14,14 +38,35 @@ pub type RewardDestination =
 
 pub use api_dev::api::data_availability::calls::types::create_application_key::Key;
 pub use api_dev::api::data_availability::calls::types::submit_data::Data;
-pub use api_dev::api::runtime_types::frame_support::dispatch::DispatchFeeModifier;
+pub use api_dev::api::runtime_types::frame_support::dispatch::{DispatchFeeModifier, DispatchInfo};
 pub use api_dev::api::runtime_types::pallet_staking::ValidatorPrefs;
 pub use subxt::config::polkadot::H256;
 pub use subxt_signer::{sr25519::Keypair, SecretUri};
 
 pub use api_dev::api as avail;
+pub use app_client::AppClient;
 pub use config::*;
+pub use error::ClientError;
+#[cfg(not(target_arch = "wasm32"))]
+pub use failover::{FailoverClient, FailoverPolicy};
+pub use from_substrate::{
+	ApplyExtrinsicResult, DispatchClass, DispatchOutcome, ExtrinsicOrHash, InvalidTransaction,
+	RuntimeDispatchInfo, TransactionValidityError, UnknownTransaction, Weight,
+};
+#[cfg(not(target_arch = "wasm32"))]
+pub use limit::{ConcurrencyLimit, LimitClient};
+#[cfg(not(target_arch = "wasm32"))]
+pub use reconnect::ReconnectingClient;
+#[cfg(not(target_arch = "wasm32"))]
+pub use retry::{RetryClient, RetryPolicy};
+pub use rpcs::ReadMode;
+#[cfg(not(target_arch = "wasm32"))]
+pub use sdk::ClientOptions;
 pub use sdk::{WaitFor, SDK};
+#[cfg(not(target_arch = "wasm32"))]
+pub use submission_queue::SubmissionQueue;
+#[cfg(all(feature = "tracing", not(target_arch = "wasm32")))]
+pub use traced::TracingClient;
 
 pub use crate::avail::runtime_types::sp_arithmetic::per_things::Perbill;
 pub use avail_core;
@@ -35,9 +80,11 @@ pub use sp_core;
 pub use subxt;
 pub use subxt::config::polkadot::U256;
 pub use subxt_signer;
-pub use transactions::{Mortality, NewCommission, Nonce, Options};
+pub use transactions::{Mortality, NewCommission, Nonce, Options, SignatureScheme, Tip};
 pub use utils::utils_raw;
-pub use utils::FetchTransactionError;
+pub use utils::{
+	AccountBalance, ExtrinsicResult, FetchTransactionError, OfflineMortality, OfflineParams,
+};
 
 pub mod nomination_pools_types {
 	pub use crate::avail::nomination_pools::calls::types::set_claim_permission::Permission;
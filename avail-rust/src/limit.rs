@@ -0,0 +1,164 @@
+use serde_json::value::RawValue;
+use std::sync::{
+	atomic::{AtomicUsize, Ordering},
+	Arc,
+};
+use subxt::backend::rpc::{RawRpcFuture, RawRpcSubscription, RpcClientT};
+use tokio::sync::{Mutex, Semaphore};
+
+/// Maximum number of requests [`LimitClient`] lets through at the same time. Clones share
+/// the same limit, so a clone kept around can be used to tune or monitor it while the SDK is
+/// running.
+#[derive(Clone)]
+pub struct ConcurrencyLimit {
+	inner: Arc<LimitState>,
+}
+
+struct LimitState {
+	semaphore: Semaphore,
+	limit: AtomicUsize,
+	in_flight: AtomicUsize,
+	resize: Mutex<()>,
+}
+
+impl ConcurrencyLimit {
+	/// A limit of zero is treated as one.
+	pub fn new(limit: usize) -> Self {
+		let limit = limit.max(1);
+		Self {
+			inner: Arc::new(LimitState {
+				semaphore: Semaphore::new(limit),
+				limit: AtomicUsize::new(limit),
+				in_flight: AtomicUsize::new(0),
+				resize: Mutex::new(()),
+			}),
+		}
+	}
+
+	pub fn limit(&self) -> usize {
+		self.inner.limit.load(Ordering::SeqCst)
+	}
+
+	/// Number of requests currently being processed. Queued requests are not counted.
+	pub fn in_flight(&self) -> usize {
+		self.inner.in_flight.load(Ordering::SeqCst)
+	}
+
+	/// Changes the limit. Lowering it waits until enough of the requests in flight have
+	/// completed. A limit of zero is treated as one.
+	pub async fn set_limit(&self, limit: usize) {
+		let limit = limit.max(1);
+		let _guard = self.inner.resize.lock().await;
+		let current = self.limit();
+
+		if limit > current {
+			self.inner.semaphore.add_permits(limit - current);
+		} else if limit < current {
+			let removed = (current - limit) as u32;
+			if let Ok(permits) = self.inner.semaphore.acquire_many(removed).await {
+				permits.forget();
+			}
+		}
+		self.inner.limit.store(limit, Ordering::SeqCst);
+	}
+}
+
+impl std::fmt::Debug for ConcurrencyLimit {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.debug_struct("ConcurrencyLimit")
+			.field("limit", &self.limit())
+			.field("in_flight", &self.in_flight())
+			.finish()
+	}
+}
+
+/// RPC client that queues requests once [`ConcurrencyLimit`] requests are in flight, instead
+/// of failing them. Only establishing a subscription counts towards the limit, not its
+/// lifetime.
+pub struct LimitClient<C> {
+	inner: C,
+	limit: ConcurrencyLimit,
+}
+
+impl<C: RpcClientT> LimitClient<C> {
+	pub fn new(inner: C, limit: ConcurrencyLimit) -> Self {
+		Self { inner, limit }
+	}
+}
+
+impl<C: RpcClientT> RpcClientT for LimitClient<C> {
+	fn request_raw<'a>(
+		&'a self,
+		method: &'a str,
+		params: Option<Box<RawValue>>,
+	) -> RawRpcFuture<'a, Box<RawValue>> {
+		Box::pin(async move {
+			let _permit = self.limit.inner.semaphore.acquire().await;
+			let _in_flight = InFlight::new(&self.limit.inner.in_flight);
+			self.inner.request_raw(method, params).await
+		})
+	}
+
+	fn subscribe_raw<'a>(
+		&'a self,
+		sub: &'a str,
+		params: Option<Box<RawValue>>,
+		unsub: &'a str,
+	) -> RawRpcFuture<'a, RawRpcSubscription> {
+		Box::pin(async move {
+			let _permit = self.limit.inner.semaphore.acquire().await;
+			let _in_flight = InFlight::new(&self.limit.inner.in_flight);
+			self.inner.subscribe_raw(sub, params, unsub).await
+		})
+	}
+}
+
+/// Counts a request as in flight until dropped, so cancelled requests are accounted for too.
+struct InFlight<'a>(&'a AtomicUsize);
+
+impl<'a> InFlight<'a> {
+	fn new(counter: &'a AtomicUsize) -> Self {
+		counter.fetch_add(1, Ordering::SeqCst);
+		Self(counter)
+	}
+}
+
+impl Drop for InFlight<'_> {
+	fn drop(&mut self) {
+		self.0.fetch_sub(1, Ordering::SeqCst);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_client::TestClient;
+	use std::time::Duration;
+
+	#[tokio::test]
+	async fn requests_beyond_the_limit_are_queued() {
+		let limit = ConcurrencyLimit::new(2);
+		let inner = TestClient::new().delay(Duration::from_millis(10));
+		let client = LimitClient::new(inner, limit.clone());
+
+		let requests = (0..5).map(|_| client.request_raw("chain_getHeader", None));
+		let results = futures::future::join_all(requests).await;
+
+		assert!(results.iter().all(|r| r.is_ok()));
+		assert_eq!(client.inner.max_in_flight(), 2);
+		assert_eq!(limit.in_flight(), 0);
+	}
+
+	#[tokio::test]
+	async fn limit_can_be_changed() {
+		let limit = ConcurrencyLimit::new(4);
+
+		limit.set_limit(1).await;
+		assert_eq!(limit.limit(), 1);
+		assert_eq!(limit.inner.semaphore.available_permits(), 1);
+
+		limit.set_limit(3).await;
+		assert_eq!(limit.limit(), 3);
+		assert_eq!(limit.inner.semaphore.available_permits(), 3);
+	}
+}
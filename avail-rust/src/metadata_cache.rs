@@ -0,0 +1,107 @@
+use crate::{AvailConfig, BlockHash};
+use codec::{Decode, Encode};
+use std::path::{Path, PathBuf};
+use subxt::{backend::Backend, client::RuntimeVersion, Metadata};
+
+/// File layout of a cached metadata. The genesis hash and spec version are stored next to
+/// the metadata so a stale or foreign file is never trusted.
+#[derive(Encode, Decode)]
+struct CachedMetadata {
+	genesis_hash: BlockHash,
+	spec_version: u32,
+	metadata: Vec<u8>,
+}
+
+/// Name of the cache file for the given chain and runtime inside `dir`.
+pub(crate) fn cache_path(dir: &Path, genesis_hash: BlockHash, spec_version: u32) -> PathBuf {
+	dir.join(std::format!("{:?}-{}.scale", genesis_hash, spec_version))
+}
+
+/// SCALE encoded metadata as returned by the runtime. V15 is preferred, same as
+/// [`crate::SDK::refresh_runtime_version`].
+pub(crate) async fn fetch_raw_metadata(
+	backend: &dyn Backend<AvailConfig>,
+	at: BlockHash,
+) -> Result<Vec<u8>, subxt::Error> {
+	let version = 15u32.encode();
+	let v15 = backend
+		.call("Metadata_metadata_at_version", Some(&version), at)
+		.await?;
+	if let Ok(Some(metadata)) = Option::<Vec<u8>>::decode(&mut v15.as_slice()) {
+		return Ok(metadata);
+	}
+
+	let legacy = backend.call("Metadata_metadata", None, at).await?;
+	Ok(Vec::<u8>::decode(&mut legacy.as_slice())?)
+}
+
+pub(crate) fn save(
+	path: &Path,
+	genesis_hash: BlockHash,
+	runtime_version: &RuntimeVersion,
+	metadata: Vec<u8>,
+) -> Result<(), std::io::Error> {
+	if let Some(dir) = path.parent() {
+		std::fs::create_dir_all(dir)?;
+	}
+
+	let cached = CachedMetadata {
+		genesis_hash,
+		spec_version: runtime_version.spec_version,
+		metadata,
+	};
+	std::fs::write(path, cached.encode())
+}
+
+/// Reads the metadata stored at `path`. Fails if the file does not belong to the given chain
+/// and runtime.
+pub(crate) fn load(
+	path: &Path,
+	genesis_hash: BlockHash,
+	spec_version: u32,
+) -> Result<Metadata, String> {
+	let file = std::fs::read(path).map_err(|e| e.to_string())?;
+	let cached = CachedMetadata::decode(&mut file.as_slice()).map_err(|e| e.to_string())?;
+
+	if cached.genesis_hash != genesis_hash {
+		return Err(std::format!(
+			"Cached metadata belongs to a different chain. Expected: {:?}, Actual: {:?}",
+			genesis_hash,
+			cached.genesis_hash
+		));
+	}
+	if cached.spec_version != spec_version {
+		return Err(std::format!(
+			"Cached metadata is outdated. Expected spec version: {}, Actual: {}",
+			spec_version,
+			cached.spec_version
+		));
+	}
+
+	Metadata::decode(&mut cached.metadata.as_slice()).map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn stale_cache_is_rejected() {
+		let dir = std::env::temp_dir().join("avail-rust-metadata-cache-test");
+		let genesis_hash = BlockHash::repeat_byte(1);
+		let runtime_version = RuntimeVersion {
+			spec_version: 10,
+			transaction_version: 1,
+		};
+		let path = cache_path(&dir, genesis_hash, runtime_version.spec_version);
+		save(&path, genesis_hash, &runtime_version, vec![1, 2, 3]).unwrap();
+
+		let error = load(&path, genesis_hash, 11).unwrap_err();
+		assert!(error.contains("outdated"));
+
+		let error = load(&path, BlockHash::repeat_byte(2), 10).unwrap_err();
+		assert!(error.contains("different chain"));
+
+		std::fs::remove_dir_all(dir).unwrap();
+	}
+}
@@ -0,0 +1,424 @@
+//! Metadata hash of RFC-78, which the `CheckMetadataHash` signed extension adds to the signed
+//! data so that a signer can check the metadata it decodes transactions with.
+//!
+//! Only the types reachable from the extrinsic, i.e. the call, address, signature and signed
+//! extension types, are hashed. They are numbered in the order of their id in the metadata and
+//! every variant of an enum is a leaf of its own. The root of the tree of these leaves is then
+//! hashed together with the extrinsic metadata and the chain details.
+
+use codec::{Compact, Decode, Encode};
+use scale_info::{form::PortableForm, PortableRegistry, TypeDef, TypeDefPrimitive};
+use sp_core::hashing::blake2_256;
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use subxt::Metadata;
+
+type Hash = [u8; 32];
+
+#[derive(Encode)]
+enum TypeRef {
+	Bool,
+	Char,
+	Str,
+	U8,
+	U16,
+	U32,
+	U64,
+	U128,
+	U256,
+	I8,
+	I16,
+	I32,
+	I64,
+	I128,
+	I256,
+	CompactU8,
+	CompactU16,
+	CompactU32,
+	CompactU64,
+	CompactU128,
+	CompactU256,
+	Void,
+	ById(Compact<u32>),
+}
+
+#[derive(Encode)]
+struct Field {
+	name: Option<String>,
+	ty: TypeRef,
+	type_name: Option<String>,
+}
+
+#[derive(Encode)]
+struct EnumerationVariant {
+	name: String,
+	fields: Vec<Field>,
+	index: Compact<u32>,
+}
+
+#[derive(Encode)]
+enum TypeDefinition {
+	Composite(Vec<Field>),
+	Enumeration(EnumerationVariant),
+	Sequence(TypeRef),
+	Array {
+		len: u32,
+		type_param: TypeRef,
+	},
+	Tuple(Vec<TypeRef>),
+	BitSequence {
+		num_bytes: u8,
+		least_significant_bit_first: bool,
+	},
+}
+
+#[derive(Encode)]
+struct Type {
+	path: Vec<String>,
+	type_def: TypeDefinition,
+	type_id: Compact<u32>,
+}
+
+#[derive(Encode)]
+struct SignedExtensionMetadata {
+	identifier: String,
+	included_in_extrinsic: TypeRef,
+	included_in_signed_data: TypeRef,
+}
+
+#[derive(Encode)]
+struct ExtrinsicMetadata {
+	version: u8,
+	address_ty: TypeRef,
+	call_ty: TypeRef,
+	signature_ty: TypeRef,
+	signed_extensions: Vec<SignedExtensionMetadata>,
+}
+
+#[derive(Encode)]
+enum MetadataDigest {
+	#[codec(index = 1)]
+	V1 {
+		type_information_tree_root: Hash,
+		extrinsic_metadata_hash: Hash,
+		spec_version: u32,
+		spec_name: String,
+		base58_prefix: u16,
+		decimals: u8,
+		token_symbol: String,
+	},
+}
+
+/// Metadata hash of the runtime `spec_name` at `spec_version`, described by `metadata`.
+/// `decimals` and `token_symbol` are the ones the runtime was built with, e.g. `18` and
+/// `"AVAIL"`. The SS58 prefix is read from the `System::SS58Prefix` constant.
+pub fn compute(
+	metadata: &Metadata,
+	spec_name: &str,
+	spec_version: u32,
+	decimals: u8,
+	token_symbol: &str,
+) -> Result<[u8; 32], String> {
+	let registry = metadata.types();
+	let extrinsic = metadata.extrinsic();
+
+	let mut roots = vec![
+		extrinsic.address_ty(),
+		extrinsic.call_ty(),
+		extrinsic.signature_ty(),
+	];
+	for extension in extrinsic.signed_extensions() {
+		roots.push(extension.extra_ty());
+		roots.push(extension.additional_ty());
+	}
+
+	let mut collected = BTreeSet::new();
+	for id in roots {
+		collect(registry, id, &mut collected)?;
+	}
+	let ids: BTreeMap<u32, u32> = collected
+		.into_iter()
+		.enumerate()
+		.map(|(new_id, id)| (id, new_id as u32))
+		.collect();
+
+	let mut leaves = Vec::new();
+	for (id, new_id) in &ids {
+		for ty in types(registry, *id, *new_id, &ids)? {
+			leaves.push(blake2_256(&ty.encode()));
+		}
+	}
+
+	let mut signed_extensions = Vec::new();
+	for extension in extrinsic.signed_extensions() {
+		signed_extensions.push(SignedExtensionMetadata {
+			identifier: extension.identifier().to_string(),
+			included_in_extrinsic: type_ref(registry, extension.extra_ty(), &ids)?,
+			included_in_signed_data: type_ref(registry, extension.additional_ty(), &ids)?,
+		});
+	}
+	let extrinsic_metadata = ExtrinsicMetadata {
+		version: extrinsic.version(),
+		address_ty: type_ref(registry, extrinsic.address_ty(), &ids)?,
+		call_ty: type_ref(registry, extrinsic.call_ty(), &ids)?,
+		signature_ty: type_ref(registry, extrinsic.signature_ty(), &ids)?,
+		signed_extensions,
+	};
+
+	let base58_prefix = metadata
+		.pallet_by_name("System")
+		.and_then(|pallet| pallet.constant_by_name("SS58Prefix"))
+		.ok_or_else(|| String::from("System::SS58Prefix is missing from the metadata"))?;
+	let base58_prefix = u16::decode(&mut base58_prefix.value()).map_err(|e| e.to_string())?;
+
+	let digest = MetadataDigest::V1 {
+		type_information_tree_root: tree_root(leaves),
+		extrinsic_metadata_hash: blake2_256(&extrinsic_metadata.encode()),
+		spec_version,
+		spec_name: spec_name.to_string(),
+		base58_prefix,
+		decimals,
+		token_symbol: token_symbol.to_string(),
+	};
+	Ok(blake2_256(&digest.encode()))
+}
+
+/// Root of the binary tree over `leaves`. The last two nodes are hashed together and the
+/// result is put in front, until a single node is left.
+fn tree_root(leaves: Vec<Hash>) -> Hash {
+	let mut nodes = VecDeque::from(leaves);
+	while nodes.len() > 1 {
+		let right = nodes.pop_back().unwrap_or_default();
+		let left = nodes.pop_back().unwrap_or_default();
+		nodes.push_front(blake2_256(&(left, right).encode()));
+	}
+	nodes.pop_back().unwrap_or_default()
+}
+
+fn resolve(
+	registry: &PortableRegistry,
+	id: u32,
+) -> Result<&scale_info::Type<PortableForm>, String> {
+	registry
+		.resolve(id)
+		.ok_or_else(|| std::format!("Type {} is missing from the metadata", id))
+}
+
+/// Whether the type is referenced inline rather than by id.
+fn is_inline(type_def: &TypeDef<PortableForm>) -> bool {
+	matches!(type_def, TypeDef::Primitive(_) | TypeDef::Compact(_)) || is_void(type_def)
+}
+
+fn is_void(type_def: &TypeDef<PortableForm>) -> bool {
+	match type_def {
+		TypeDef::Composite(composite) => composite.fields.is_empty(),
+		TypeDef::Tuple(tuple) => tuple.fields.is_empty(),
+		TypeDef::Variant(variant) => variant.variants.is_empty(),
+		_ => false,
+	}
+}
+
+/// Adds `id` and every type it refers to, except for inlined types, to `collected`.
+fn collect(
+	registry: &PortableRegistry,
+	id: u32,
+	collected: &mut BTreeSet<u32>,
+) -> Result<(), String> {
+	let ty = resolve(registry, id)?;
+	if is_inline(&ty.type_def) || !collected.insert(id) {
+		return Ok(());
+	}
+
+	let children: Vec<u32> = match &ty.type_def {
+		TypeDef::Composite(composite) => composite.fields.iter().map(|f| f.ty.id).collect(),
+		TypeDef::Variant(variant) => variant
+			.variants
+			.iter()
+			.flat_map(|v| v.fields.iter().map(|f| f.ty.id))
+			.collect(),
+		TypeDef::Sequence(sequence) => vec![sequence.type_param.id],
+		TypeDef::Array(array) => vec![array.type_param.id],
+		TypeDef::Tuple(tuple) => tuple.fields.iter().map(|f| f.id).collect(),
+		_ => Vec::new(),
+	};
+	for child in children {
+		collect(registry, child, collected)?;
+	}
+	Ok(())
+}
+
+fn type_ref(
+	registry: &PortableRegistry,
+	id: u32,
+	ids: &BTreeMap<u32, u32>,
+) -> Result<TypeRef, String> {
+	let ty = resolve(registry, id)?;
+	let type_ref = match &ty.type_def {
+		TypeDef::Primitive(primitive) => primitive_ref(primitive),
+		TypeDef::Compact(compact) => compact_ref(registry, compact.type_param.id)?,
+		type_def if is_void(type_def) => TypeRef::Void,
+		_ => {
+			let new_id = ids
+				.get(&id)
+				.ok_or_else(|| std::format!("Type {} was not collected", id))?;
+			TypeRef::ById(Compact(*new_id))
+		},
+	};
+	Ok(type_ref)
+}
+
+fn primitive_ref(primitive: &TypeDefPrimitive) -> TypeRef {
+	match primitive {
+		TypeDefPrimitive::Bool => TypeRef::Bool,
+		TypeDefPrimitive::Char => TypeRef::Char,
+		TypeDefPrimitive::Str => TypeRef::Str,
+		TypeDefPrimitive::U8 => TypeRef::U8,
+		TypeDefPrimitive::U16 => TypeRef::U16,
+		TypeDefPrimitive::U32 => TypeRef::U32,
+		TypeDefPrimitive::U64 => TypeRef::U64,
+		TypeDefPrimitive::U128 => TypeRef::U128,
+		TypeDefPrimitive::U256 => TypeRef::U256,
+		TypeDefPrimitive::I8 => TypeRef::I8,
+		TypeDefPrimitive::I16 => TypeRef::I16,
+		TypeDefPrimitive::I32 => TypeRef::I32,
+		TypeDefPrimitive::I64 => TypeRef::I64,
+		TypeDefPrimitive::I128 => TypeRef::I128,
+		TypeDefPrimitive::I256 => TypeRef::I256,
+	}
+}
+
+/// Compact encoded type `id`. Wrappers around a single field, such as `Perbill`, are compact
+/// encoded as that field.
+fn compact_ref(registry: &PortableRegistry, id: u32) -> Result<TypeRef, String> {
+	let ty = resolve(registry, id)?;
+	let type_ref = match &ty.type_def {
+		TypeDef::Primitive(TypeDefPrimitive::U8) => TypeRef::CompactU8,
+		TypeDef::Primitive(TypeDefPrimitive::U16) => TypeRef::CompactU16,
+		TypeDef::Primitive(TypeDefPrimitive::U32) => TypeRef::CompactU32,
+		TypeDef::Primitive(TypeDefPrimitive::U64) => TypeRef::CompactU64,
+		TypeDef::Primitive(TypeDefPrimitive::U128) => TypeRef::CompactU128,
+		TypeDef::Primitive(TypeDefPrimitive::U256) => TypeRef::CompactU256,
+		TypeDef::Composite(composite) if composite.fields.len() == 1 => {
+			compact_ref(registry, composite.fields[0].ty.id)?
+		},
+		type_def if is_void(type_def) => TypeRef::Void,
+		_ => return Err(std::format!("Type {} cannot be compact encoded", id)),
+	};
+	Ok(type_ref)
+}
+
+fn fields(
+	registry: &PortableRegistry,
+	fields: &[scale_info::Field<PortableForm>],
+	ids: &BTreeMap<u32, u32>,
+) -> Result<Vec<Field>, String> {
+	fields
+		.iter()
+		.map(|field| {
+			Ok(Field {
+				name: field.name.clone(),
+				ty: type_ref(registry, field.ty.id, ids)?,
+				type_name: field.type_name.clone(),
+			})
+		})
+		.collect()
+}
+
+/// Leaves of the type `id`, numbered `new_id`. Enums have a leaf for each variant, ordered by
+/// their index; every other type has a single leaf.
+fn types(
+	registry: &PortableRegistry,
+	id: u32,
+	new_id: u32,
+	ids: &BTreeMap<u32, u32>,
+) -> Result<Vec<Type>, String> {
+	let ty = resolve(registry, id)?;
+	let type_defs = match &ty.type_def {
+		TypeDef::Composite(composite) => {
+			let fields = fields(registry, &composite.fields, ids)?;
+			vec![TypeDefinition::Composite(fields)]
+		},
+		TypeDef::Variant(variant) => {
+			let mut variants: Vec<_> = variant.variants.iter().collect();
+			variants.sort_by_key(|v| v.index);
+
+			let mut type_defs = Vec::new();
+			for v in variants {
+				type_defs.push(TypeDefinition::Enumeration(EnumerationVariant {
+					name: v.name.clone(),
+					fields: fields(registry, &v.fields, ids)?,
+					index: Compact(v.index as u32),
+				}));
+			}
+			type_defs
+		},
+		TypeDef::Sequence(sequence) => {
+			let type_param = type_ref(registry, sequence.type_param.id, ids)?;
+			vec![TypeDefinition::Sequence(type_param)]
+		},
+		TypeDef::Array(array) => vec![TypeDefinition::Array {
+			len: array.len,
+			type_param: type_ref(registry, array.type_param.id, ids)?,
+		}],
+		TypeDef::Tuple(tuple) => {
+			let refs = tuple.fields.iter().map(|f| type_ref(registry, f.id, ids));
+			vec![TypeDefinition::Tuple(refs.collect::<Result<_, _>>()?)]
+		},
+		TypeDef::BitSequence(bits) => {
+			let num_bytes = match &resolve(registry, bits.bit_store_type.id)?.type_def {
+				TypeDef::Primitive(TypeDefPrimitive::U8) => 1,
+				TypeDef::Primitive(TypeDefPrimitive::U16) => 2,
+				TypeDef::Primitive(TypeDefPrimitive::U32) => 4,
+				TypeDef::Primitive(TypeDefPrimitive::U64) => 8,
+				_ => return Err(std::format!("Type {} has an invalid bit store", id)),
+			};
+			let order = resolve(registry, bits.bit_order_type.id)?;
+			let least_significant_bit_first =
+				order.path.segments.last().is_some_and(|s| s == "Lsb0");
+			vec![TypeDefinition::BitSequence {
+				num_bytes,
+				least_significant_bit_first,
+			}]
+		},
+		TypeDef::Primitive(_) | TypeDef::Compact(_) => {
+			return Err(std::format!("Type {} is referenced inline", id))
+		},
+	};
+
+	let types = type_defs.into_iter().map(|type_def| Type {
+		path: ty.path.segments.clone(),
+		type_def,
+		type_id: Compact(new_id),
+	});
+	Ok(types.collect())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn metadata() -> Metadata {
+		let metadata = include_bytes!("../../avail-subxt/avail.metadata.scale");
+		Metadata::decode(&mut metadata.as_slice()).unwrap()
+	}
+
+	#[test]
+	fn tree_root_hashes_nodes_from_the_back() {
+		let (a, b, c) = ([1u8; 32], [2u8; 32], [3u8; 32]);
+		let node = |left: Hash, right: Hash| blake2_256(&(left, right).encode());
+
+		assert_eq!(tree_root(Vec::new()), [0u8; 32]);
+		assert_eq!(tree_root(vec![a]), a);
+		assert_eq!(tree_root(vec![a, b]), node(a, b));
+		assert_eq!(tree_root(vec![a, b, c]), node(node(b, c), a));
+	}
+
+	#[test]
+	fn hash_covers_chain_details() {
+		let metadata = metadata();
+		let hash = compute(&metadata, "avail", 24, 18, "AVAIL").unwrap();
+
+		assert_eq!(compute(&metadata, "avail", 24, 18, "AVAIL").unwrap(), hash);
+		assert_ne!(compute(&metadata, "avail", 25, 18, "AVAIL").unwrap(), hash);
+		assert_ne!(compute(&metadata, "avail", 24, 12, "AVAIL").unwrap(), hash);
+	}
+}
@@ -0,0 +1,198 @@
+use serde_json::value::RawValue;
+use std::sync::{Arc, Mutex};
+use subxt::{
+	backend::rpc::{RawRpcFuture, RawRpcSubscription, RpcClientT},
+	error::RpcError,
+};
+
+struct Response {
+	method: String,
+	params: Option<String>,
+	value: Box<RawValue>,
+}
+
+#[derive(Default)]
+struct Inner {
+	responses: Vec<Response>,
+	calls: Vec<String>,
+}
+
+/// Transport answering requests with canned responses, for testing without a node.
+///
+/// Responses are matched by method name and params. A response registered without params
+/// answers every request of that method that has no more specific response. Clones share
+/// the same responses and call log, so a clone can be kept to inspect the calls after the
+/// transport has been handed to `Rpc::from_client` or [`crate::SDK::with_transport`].
+#[derive(Clone, Default)]
+pub struct MockTransport {
+	inner: Arc<Mutex<Inner>>,
+}
+
+impl MockTransport {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Answers `method` with `value`. If `params` is given, only requests with exactly these
+	/// params are answered.
+	pub fn on(
+		self,
+		method: &str,
+		params: Option<serde_json::Value>,
+		value: serde_json::Value,
+	) -> Self {
+		let response = Response {
+			method: method.to_string(),
+			params: params.map(|p| p.to_string()),
+			value: RawValue::from_string(value.to_string())
+				.expect("serde_json::Value is valid JSON"),
+		};
+		self.inner.lock().unwrap().responses.push(response);
+		self
+	}
+
+	/// Methods of all requests received so far, in order.
+	pub fn calls(&self) -> Vec<String> {
+		self.inner.lock().unwrap().calls.clone()
+	}
+
+	fn respond(&self, method: &str, params: Option<&RawValue>) -> Option<Box<RawValue>> {
+		let params = params.map(|p| normalize(p.get()));
+
+		let mut inner = self.inner.lock().unwrap();
+		inner.calls.push(method.to_string());
+
+		let responses = inner.responses.iter().filter(|r| r.method == method);
+		let exact = responses
+			.clone()
+			.find(|r| r.params.is_some() && r.params == params);
+		let any = responses.clone().find(|r| r.params.is_none());
+		exact.or(any).map(|r| r.value.clone())
+	}
+}
+
+/// Re-serializes JSON so formatting differences don't affect matching.
+fn normalize(json: &str) -> String {
+	serde_json::from_str::<serde_json::Value>(json)
+		.map(|v| v.to_string())
+		.unwrap_or_else(|_| json.to_string())
+}
+
+impl RpcClientT for MockTransport {
+	fn request_raw<'a>(
+		&'a self,
+		method: &'a str,
+		params: Option<Box<RawValue>>,
+	) -> RawRpcFuture<'a, Box<RawValue>> {
+		Box::pin(async move {
+			match self.respond(method, params.as_deref()) {
+				Some(value) => Ok(value),
+				None => Err(RpcError::RequestRejected(std::format!(
+					"No mock response for {}",
+					method
+				))),
+			}
+		})
+	}
+
+	fn subscribe_raw<'a>(
+		&'a self,
+		sub: &'a str,
+		_params: Option<Box<RawValue>>,
+		_unsub: &'a str,
+	) -> RawRpcFuture<'a, RawRpcSubscription> {
+		Box::pin(async move {
+			Err(RpcError::RequestRejected(std::format!(
+				"Subscriptions are not supported by the mock: {}",
+				sub
+			)))
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::avail::runtime_types::bounded_collections::bounded_vec::BoundedVec;
+	use crate::rpcs::Rpc;
+	use crate::utils_raw::{create_partial_signed_offline, sign_with_verified_signature};
+	use crate::{BlockHash, OfflineParams, Signature};
+	use codec::{Compact, Decode, Encode};
+	use serde_json::json;
+	use subxt::{backend::rpc::RpcClient, Metadata};
+
+	const ALICE: &str = "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY";
+	const BOB: &str = "5FHneW46xGXgs5mUiveU4sbTyGBzmstUspZC92UhjJM694ty";
+
+	#[tokio::test]
+	async fn answers_by_method_and_params() {
+		let mock = MockTransport::new()
+			.on("system_accountNextIndex", Some(json!([ALICE])), json!(7))
+			.on("system_accountNextIndex", None, json!(0));
+		let rpc = Rpc::from_client(RpcClient::new(mock.clone()));
+
+		let alice = rpc.system.account_next_index(ALICE.to_string()).await;
+		let bob = rpc.system.account_next_index(BOB.to_string()).await;
+
+		assert_eq!(alice.unwrap(), 7);
+		assert_eq!(bob.unwrap(), 0);
+		assert_eq!(mock.calls().len(), 2);
+	}
+
+	#[tokio::test]
+	async fn fails_without_response() {
+		let mock = MockTransport::new();
+		let rpc = Rpc::from_client(RpcClient::new(mock.clone()));
+
+		let result = rpc.system.chain().await;
+
+		assert!(result.is_err());
+		assert_eq!(mock.calls(), vec![String::from("system_chain")]);
+	}
+
+	#[tokio::test]
+	async fn builds_and_signs_payload() {
+		let genesis_hash = BlockHash::repeat_byte(0xab);
+		let mock = MockTransport::new()
+			.on("chain_getBlockHash", Some(json!([0])), json!(genesis_hash))
+			.on(
+				"state_getRuntimeVersion",
+				None,
+				json!({ "specVersion": 24, "transactionVersion": 1 }),
+			)
+			.on("system_accountNextIndex", Some(json!([ALICE])), json!(7));
+		let rpc = Rpc::from_client(RpcClient::new(mock));
+
+		let genesis = rpc.chain.get_block_hash(Some(0)).await.unwrap();
+		let version = rpc.legacy_methods.state_get_runtime_version(None).await;
+		let version = version.unwrap();
+		let nonce = rpc.system.account_next_index(ALICE.to_string()).await;
+		let params = OfflineParams {
+			genesis_hash: genesis,
+			spec_version: version.spec_version,
+			transaction_version: version.transaction_version,
+			nonce: nonce.unwrap() as u64,
+			mortality: None,
+			tip: 0,
+			app_id: 0,
+		};
+
+		let metadata = include_bytes!("../../avail-subxt/avail.metadata.scale");
+		let metadata = Metadata::decode(&mut metadata.as_slice()).unwrap();
+		let data = BoundedVec(b"data".to_vec());
+		let call = crate::avail::tx().data_availability().submit_data(data);
+		let partial = create_partial_signed_offline(&call.unvalidated(), metadata, params).unwrap();
+
+		// Era, nonce, tip and app id, then spec version, transaction version, genesis hash and
+		// the genesis hash again as the checkpoint of an immortal transaction.
+		let mut expected = (0u8, Compact(7u32), Compact(0u128), Compact(0u32)).encode();
+		expected.extend((24u32, 1u32, genesis_hash, genesis_hash).encode());
+		let payload = partial.signer_payload();
+		assert!(payload.ends_with(&expected));
+
+		let alice = subxt_signer::sr25519::dev::alice();
+		let signature = Signature::Sr25519(alice.sign(&payload).0);
+		let account_id = alice.public_key().to_account_id();
+		assert!(sign_with_verified_signature(&partial, &account_id, &signature).is_ok());
+	}
+}
@@ -1,10 +1,13 @@
 use super::extrinsics_params::OnlyCodecExtra;
+use crate::avail::runtime_types::da_control::pallet::Call as DataAvailabilityCall;
 use crate::{avail::runtime_types::da_runtime::RuntimeCall, Address, Signature};
 
 use codec::{Compact, Decode, Encode, EncodeLike, Error, Input};
 use serde::{Deserialize, Serialize};
 use std::mem::size_of;
 use subxt::backend::legacy::rpc_methods::Bytes;
+use subxt_core::config::{substrate::BlakeTwo256, Hasher};
+use subxt_core::utils::{Era, H256};
 
 pub type SignaturePayload = (Address, Signature, OnlyCodecExtra);
 
@@ -38,6 +41,28 @@ impl AppUncheckedExtrinsic {
 		output
 	}
 
+	/// Unsigned extrinsic carrying `function`, as used for inherents and other calls that are
+	/// not signed. Its encoding is the length prefixed version byte `0x04` followed by the call.
+	pub fn new_unsigned(function: RuntimeCall) -> Self {
+		Self {
+			signature: None,
+			function,
+		}
+	}
+
+	/// Decodes a `0x` prefixed, hex encoded extrinsic such as the one produced by an
+	/// offline signer.
+	pub fn from_hex(value: &str) -> Result<Self, String> {
+		let value = crate::utils_raw::hex_decode_prefixed(value)?;
+		Self::decode(&mut value.as_slice()).map_err(|e| e.to_string())
+	}
+
+	/// Blake2-256 hash of the encoded extrinsic. This is the same hash the node
+	/// returns on submission.
+	pub fn hash(&self) -> H256 {
+		BlakeTwo256::hash_of(self)
+	}
+
 	pub fn app_id(&self) -> crate::AppId {
 		self.signature
 			.as_ref()
@@ -45,6 +70,36 @@ impl AppUncheckedExtrinsic {
 			.unwrap_or_default()
 			.into()
 	}
+
+	/// Data of a `DataAvailability::submit_data` call. `None` for every other call.
+	pub fn submitted_data(&self) -> Option<&[u8]> {
+		match &self.function {
+			RuntimeCall::DataAvailability(DataAvailabilityCall::submit_data { data }) => {
+				Some(&data.0)
+			},
+			_ => None,
+		}
+	}
+
+	/// Address of the signer. `None` for unsigned extrinsics.
+	pub fn address(&self) -> Option<&Address> {
+		self.signature.as_ref().map(|(address, _, _)| address)
+	}
+
+	/// Nonce of the signer. `None` for unsigned extrinsics.
+	pub fn nonce(&self) -> Option<u32> {
+		self.signature.as_ref().map(|(_, _, extra)| extra.5 .0)
+	}
+
+	/// Tip paid to the block author. `None` for unsigned extrinsics.
+	pub fn tip(&self) -> Option<u128> {
+		self.signature.as_ref().map(|(_, _, extra)| extra.7 .0)
+	}
+
+	/// Mortality of the extrinsic. `None` for unsigned extrinsics.
+	pub fn era(&self) -> Option<Era> {
+		self.signature.as_ref().map(|(_, _, extra)| extra.4)
+	}
 }
 
 impl Encode for AppUncheckedExtrinsic {
@@ -137,3 +192,27 @@ impl TryFrom<Bytes> for AppUncheckedExtrinsic {
 		AppUncheckedExtrinsic::decode(&mut value_as_slice).map_err(|s| s.to_string())
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::avail::runtime_types::bounded_collections::bounded_vec::BoundedVec;
+
+	#[test]
+	fn unsigned_extrinsic_encoding() {
+		let function = RuntimeCall::DataAvailability(DataAvailabilityCall::submit_data {
+			data: BoundedVec(vec![1, 2, 3]),
+		});
+		let extrinsic = AppUncheckedExtrinsic::new_unsigned(function.clone());
+		let encoded = extrinsic.encode();
+
+		let mut expected = vec![EXTRINSIC_FORMAT_VERSION];
+		expected.extend(function.encode());
+		assert_eq!(encoded[0] as usize, expected.len() << 2);
+		assert_eq!(&encoded[1..], expected.as_slice());
+		assert_eq!(
+			AppUncheckedExtrinsic::decode(&mut encoded.as_slice()).unwrap(),
+			extrinsic
+		);
+	}
+}
@@ -15,9 +15,16 @@ use subxt_core::utils::Era;
 #[derive(Debug, Clone, Copy, Default)]
 pub struct CheckAppId(pub crate::AppId);
 
+/// The `CheckMetadataHash` signed extension. It is disabled by default, in which case the
+/// mode byte is `0` and no hash is signed. [`DefaultExtrinsicParamsBuilder::metadata_hash`]
+/// enables it with the hash computed by [`crate::metadata_hash::compute`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CheckMetadataHash(pub Option<[u8; 32]>);
+
 /// Ideally, we would use avail_core::AppId but we cannot define `RefineParams` for it so we need a wrapper. Crazy
 impl<T: Config> RefineParams<T> for crate::AppId {}
 impl<T: Config> RefineParams<T> for CheckAppId {}
+impl<T: Config> RefineParams<T> for CheckMetadataHash {}
 
 /// Type used only for decoding extrinsic from blocks.
 pub type OnlyCodecExtra = (
@@ -30,6 +37,7 @@ pub type OnlyCodecExtra = (
 	(),                // CheckWeight<Runtime>,
 	Compact<u128>,     // ChargeTransactionPayment<Runtime>,
 	avail_core::AppId, // CheckAppId<Runtime>,
+	u8,                // CheckMetadataHash<Runtime>, the mode.
 );
 
 /// The default [`super::ExtrinsicParams`] implementation understands common signed extensions
@@ -44,6 +52,7 @@ pub type DefaultExtrinsicParams<T> = signed_extensions::AnyOf<
 		signed_extensions::CheckNonce,
 		signed_extensions::ChargeTransactionPayment,
 		CheckAppId,
+		CheckMetadataHash,
 	),
 >;
 
@@ -57,6 +66,8 @@ pub struct DefaultExtrinsicParamsBuilder<T: Config> {
 	nonce: Option<u64>,
 	tip: u128,
 	app_id: crate::AppId,
+	/// `None` means the metadata hash check is disabled.
+	metadata_hash: Option<[u8; 32]>,
 }
 
 struct Mortality<Hash> {
@@ -76,6 +87,7 @@ impl<T: Config> Default for DefaultExtrinsicParamsBuilder<T> {
 			tip: 0,
 			nonce: None,
 			app_id: crate::AppId(avail_core::AppId(0)),
+			metadata_hash: None,
 		}
 	}
 }
@@ -111,6 +123,12 @@ impl<T: Config> DefaultExtrinsicParamsBuilder<T> {
 		self
 	}
 
+	/// Enable the `CheckMetadataHash` extension, signing the given metadata hash
+	pub fn metadata_hash(mut self, hash: [u8; 32]) -> Self {
+		self.metadata_hash = Some(hash);
+		self
+	}
+
 	/// Make the transaction mortal, given a block number and block hash (which must both point to
 	/// the same block) that it should be mortal from, and the number of blocks (roughly; it'll be
 	/// rounded to a power of two) that it will be mortal for.
@@ -162,6 +180,7 @@ impl<T: Config> DefaultExtrinsicParamsBuilder<T> {
 			check_nonce_params,
 			charge_transaction_params,
 			self.app_id,
+			CheckMetadataHash(self.metadata_hash),
 		)
 	}
 }
@@ -189,3 +208,30 @@ impl<T: Config> subxt::config::ExtrinsicParams<T> for CheckAppId {
 		Ok(CheckAppId(id))
 	}
 }
+
+impl ExtrinsicParamsEncoder for CheckMetadataHash {
+	fn encode_extra_to(&self, v: &mut Vec<u8>) {
+		let mode: u8 = self.0.is_some().into();
+		mode.encode_to(v);
+	}
+
+	fn encode_additional_to(&self, v: &mut Vec<u8>) {
+		self.0.encode_to(v);
+	}
+}
+
+impl<T: Config> SignedExtension<T> for CheckMetadataHash {
+	type Decoded = u8;
+
+	fn matches(identifier: &str, _type_id: u32, _types: &PortableRegistry) -> bool {
+		identifier == "CheckMetadataHash"
+	}
+}
+
+impl<T: Config> subxt::config::ExtrinsicParams<T> for CheckMetadataHash {
+	type Params = CheckMetadataHash;
+
+	fn new(_client: &ClientState<T>, params: Self::Params) -> Result<Self, ExtrinsicParamsError> {
+		Ok(params)
+	}
+}
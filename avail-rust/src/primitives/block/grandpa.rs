@@ -1,9 +1,10 @@
 use codec::{Codec, Decode};
 use serde::{Serialize, Serializer};
 
-use crate::avail::runtime_types::sp_consensus_grandpa::app::Public;
+use crate::avail::runtime_types::sp_consensus_grandpa::app::{Public, Signature};
+use crate::{AvailHeader, BlockHash, BlockNumber};
 
-#[derive(Decode)]
+#[derive(Debug, Clone, Decode)]
 pub struct AuthorityId(pub Public);
 
 impl Serialize for AuthorityId {
@@ -41,3 +42,60 @@ pub enum ConsensusLog<N: Codec> {
 	#[codec(index = 5)]
 	Resume(N),
 }
+
+/// Proof that a block has been finalized, as returned by `grandpa_proveFinality`.
+#[derive(Debug, Clone, Decode)]
+pub struct FinalityProof {
+	/// The hash of the block the justification finalizes.
+	pub block: BlockHash,
+	/// SCALE encoded [`GrandpaJustification`].
+	pub justification: Vec<u8>,
+	/// Headers from the requested block up to `block`, unknown to the requester.
+	pub unknown_headers: Vec<AvailHeader>,
+}
+
+impl FinalityProof {
+	pub fn decode_justification(&self) -> Result<GrandpaJustification, codec::Error> {
+		GrandpaJustification::decode(&mut self.justification.as_slice())
+	}
+}
+
+/// A GRANDPA justification for block finality. It includes a commit message and an ancestry
+/// proof including all headers routing all precommit target blocks to the commit target block.
+#[derive(Debug, Clone, Decode)]
+pub struct GrandpaJustification {
+	pub round: u64,
+	pub commit: Commit,
+	pub votes_ancestries: Vec<AvailHeader>,
+}
+
+/// A commit message which is an aggregate of signed precommits.
+#[derive(Debug, Clone, Decode)]
+pub struct Commit {
+	/// The target block's hash.
+	pub target_hash: BlockHash,
+	/// The target block's number.
+	pub target_number: BlockNumber,
+	/// Precommits for target block or any block after it that justify this commit.
+	pub precommits: Vec<SignedPrecommit>,
+}
+
+/// A precommit for a block and its ancestors.
+#[derive(Debug, Clone, Decode)]
+pub struct Precommit {
+	/// The target block's hash.
+	pub target_hash: BlockHash,
+	/// The target block's number.
+	pub target_number: BlockNumber,
+}
+
+/// A signed precommit message.
+#[derive(Debug, Clone, Decode)]
+pub struct SignedPrecommit {
+	/// The precommit message which has been signed.
+	pub precommit: Precommit,
+	/// The signature on the message.
+	pub signature: Signature,
+	/// The Id of the signer.
+	pub id: AuthorityId,
+}
@@ -1,6 +1,8 @@
 use codec::{Decode, Encode};
 use core::marker::PhantomData;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use sp_core::storage::StateVersion;
+use subxt::backend::legacy::rpc_methods::Bytes;
 use subxt_core::config::{
 	substrate::{BlakeTwo256, Digest, DigestItem},
 	Hasher, Header,
@@ -11,6 +13,7 @@ use crate::avail::runtime_types::{
 	avail_core::header::{extension::HeaderExtension, Header as ApiHeader},
 	sp_runtime::generic::digest::{Digest as ApiDigest, DigestItem as ApiDigestItem},
 };
+use crate::AccountId;
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Encode, Decode)]
 #[serde(rename_all = "camelCase")]
@@ -31,8 +34,74 @@ impl AvailHeader {
 			HeaderExtension::V3(ext) => ext.commitment.data_root,
 		}
 	}
+
+	/// Checks that `extrinsics`, e.g. a block body fetched from an untrusted source, are the
+	/// ones committed to by this header.
+	pub fn verify_extrinsics_root(&self, extrinsics: &[Bytes]) -> bool {
+		extrinsics_root(extrinsics) == self.extrinsics_root
+	}
+
+	pub fn digest_logs(&self) -> impl Iterator<Item = &DigestItem> {
+		self.digest.logs.iter()
+	}
+
+	/// Data of the first `PreRuntime` digest item of the given consensus engine, e.g. `*b"BABE"`.
+	pub fn pre_runtime(&self, engine_id: [u8; 4]) -> Option<&[u8]> {
+		self.digest_logs().find_map(|item| match item {
+			DigestItem::PreRuntime(id, data) if *id == engine_id => Some(data.as_slice()),
+			_ => None,
+		})
+	}
+
+	/// Data of the first `Seal` digest item of the given consensus engine, e.g. `*b"BABE"`.
+	pub fn seal(&self, engine_id: [u8; 4]) -> Option<&[u8]> {
+		self.digest_logs().find_map(|item| match item {
+			DigestItem::Seal(id, data) if *id == engine_id => Some(data.as_slice()),
+			_ => None,
+		})
+	}
+
+	/// Index of the block author in the BABE authority set, read from the BABE pre-runtime
+	/// digest. Every variant of the BABE pre-digest starts with the authority index.
+	pub fn babe_authority_index(&self) -> Option<u32> {
+		babe_authority_index(self.pre_runtime(BABE_ENGINE_ID)?)
+	}
+
+	/// Authoring slot read from the Aura pre-runtime digest.
+	pub fn aura_slot(&self) -> Option<u64> {
+		u64::decode(&mut self.pre_runtime(AURA_ENGINE_ID)?).ok()
+	}
+
+	/// Account of the validator that authored this block.
+	///
+	/// `session_validators` must be the validator set of the session the block belongs to,
+	/// in the order returned by `session().validators()`. BABE is tried first, then Aura.
+	pub fn author(&self, session_validators: &[AccountId]) -> Option<AccountId> {
+		let index = match self.babe_authority_index() {
+			Some(index) => index as usize,
+			None => aura_authority_index(self.aura_slot()?, session_validators.len())?,
+		};
+
+		session_validators.get(index).cloned()
+	}
+}
+
+fn babe_authority_index(mut pre_digest: &[u8]) -> Option<u32> {
+	let _variant = u8::decode(&mut pre_digest).ok()?;
+	u32::decode(&mut pre_digest).ok()
+}
+
+fn aura_authority_index(slot: u64, authorities: usize) -> Option<usize> {
+	if authorities == 0 {
+		return None;
+	}
+
+	Some((slot % authorities as u64) as usize)
 }
 
+const BABE_ENGINE_ID: [u8; 4] = *b"BABE";
+const AURA_ENGINE_ID: [u8; 4] = *b"aura";
+
 impl Header for AvailHeader {
 	type Hasher = BlakeTwo256;
 	type Number = u32;
@@ -46,6 +115,15 @@ impl Header for AvailHeader {
 	}
 }
 
+/// Ordered trie root of the encoded extrinsics, computed the same way the runtime computes
+/// `extrinsics_root`.
+pub fn extrinsics_root(extrinsics: &[Bytes]) -> H256 {
+	use sp_runtime::traits::{BlakeTwo256 as RuntimeBlakeTwo256, Hash};
+
+	let extrinsics = extrinsics.iter().map(|e| e.0.clone()).collect();
+	RuntimeBlakeTwo256::ordered_trie_root(extrinsics, StateVersion::V0)
+}
+
 fn number_to_hex<S>(value: &u32, serializer: S) -> Result<S::Ok, S::Error>
 where
 	S: Serializer,
@@ -60,7 +138,7 @@ where
 {
 	let buf = String::deserialize(deserializer)?;
 	let without_prefix = buf.trim_start_matches("0x");
-	Ok(u32::from_str_radix(without_prefix, 16).unwrap())
+	u32::from_str_radix(without_prefix, 16).map_err(serde::de::Error::custom)
 }
 
 impl<B, H> From<AvailHeader> for ApiHeader<B, H>
@@ -102,3 +180,95 @@ impl From<DigestItem> for ApiDigestItem {
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn babe_authority_index_of_every_pre_digest_variant() {
+		// Secondary plain: variant, authority index, slot.
+		let mut plain = vec![1u8];
+		plain.extend(7u32.encode());
+		plain.extend(1234u64.encode());
+		assert_eq!(babe_authority_index(&plain), Some(7));
+
+		// Primary: variant, authority index, slot, VRF output and proof.
+		let mut primary = vec![0u8];
+		primary.extend(3u32.encode());
+		primary.extend(1234u64.encode());
+		primary.extend([0u8; 96]);
+		assert_eq!(babe_authority_index(&primary), Some(3));
+
+		assert_eq!(babe_authority_index(&[1u8, 0]), None);
+	}
+
+	#[test]
+	fn aura_authority_index_wraps_around_the_set() {
+		assert_eq!(aura_authority_index(10, 4), Some(2));
+		assert_eq!(aura_authority_index(10, 0), None);
+	}
+
+	#[test]
+	fn extrinsics_root_of_empty_body() {
+		let expected =
+			hex::decode("03170a2e7597b7b7e3d84c05391d139a62b157e78786d8c082f29dcf4c111314")
+				.unwrap();
+
+		assert_eq!(extrinsics_root(&[]), H256::from_slice(&expected));
+	}
+
+	/// Body shaped like an Avail block: the timestamp inherent, a signed `submit_data` and the
+	/// vector inherent. The signature is a placeholder, since the trie only covers the bytes.
+	/// The root was computed separately with a reference implementation of the V0 trie.
+	const BODY: [&str; 3] = [
+		"0x280403000b009cc7009001",
+		"0x1d028400d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d0124b09e6ceaf7bd94806b17cbde341e2ebdf05acf865cf8fb2e16ed3e57bc3ec64fa4992c5aefa785128cc0cda84fae3d66d3fa5e21a6930dfc1f5c057699ada9001400041d0174617661696c2065787472696e7369637320726f6f742066697874757265",
+		"0x1004270b00",
+	];
+	const BODY_ROOT: &str = "6a6fd2807baf9be1585a9c43b0f7c00cb309222ad1496b2ca6ff43c90c950691";
+
+	fn body() -> Vec<Bytes> {
+		BODY.iter()
+			.map(|e| Bytes(hex::decode(e.trim_start_matches("0x")).unwrap()))
+			.collect()
+	}
+
+	#[test]
+	fn header_commits_to_block_body() {
+		let header = AvailHeader {
+			parent_hash: H256::repeat_byte(1),
+			number: 42,
+			state_root: H256::repeat_byte(2),
+			extrinsics_root: H256::from_slice(&hex::decode(BODY_ROOT).unwrap()),
+			digest: Default::default(),
+			extension: HeaderExtension::V3(Default::default()),
+		};
+		let mut extrinsics = body();
+		assert!(header.verify_extrinsics_root(&extrinsics));
+
+		extrinsics.swap(0, 1);
+		assert!(!header.verify_extrinsics_root(&extrinsics));
+	}
+
+	#[test]
+	fn extrinsics_root_uses_state_version_0() {
+		use sp_runtime::traits::{BlakeTwo256 as RuntimeBlakeTwo256, Hash};
+
+		// The signed extrinsic is longer than 32 bytes, so V1 would store only its hash in the leaf.
+		let extrinsics = body().into_iter().map(|e| e.0).collect();
+		let v1_root = RuntimeBlakeTwo256::ordered_trie_root(extrinsics, StateVersion::V1);
+		assert_ne!(extrinsics_root(&body()), v1_root);
+	}
+
+	#[test]
+	fn extrinsics_root_depends_on_order() {
+		let a = Bytes(vec![4, 1]);
+		let b = Bytes(vec![4, 2]);
+
+		assert_ne!(
+			extrinsics_root(&[a.clone(), b.clone()]),
+			extrinsics_root(&[b, a])
+		);
+	}
+}
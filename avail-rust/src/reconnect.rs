@@ -0,0 +1,148 @@
+use futures::future::BoxFuture;
+use jsonrpsee::core::client::Error as JsonRpseeError;
+use serde_json::value::RawValue;
+use std::{future::Future, sync::Arc};
+use subxt::{
+	backend::rpc::{RawRpcFuture, RawRpcSubscription, RpcClientT},
+	error::RpcError,
+};
+use tokio::sync::RwLock;
+
+type Connect<C> = Box<dyn Fn() -> BoxFuture<'static, Result<C, RpcError>> + Send + Sync>;
+
+/// RPC client that opens a new connection once the current one is closed, e.g. because the
+/// node restarted or a load balancer dropped the WebSocket.
+///
+/// The request that finds the connection closed fails, since it may have reached the node
+/// before the connection closed, and later requests are sent over the new connection.
+/// Subscriptions made on the closed connection end and have to be made again. If opening
+/// the new connection fails, the next request tries again.
+pub struct ReconnectingClient<C> {
+	connect: Connect<C>,
+	client: RwLock<Arc<C>>,
+}
+
+impl<C: RpcClientT> ReconnectingClient<C> {
+	/// Opens the first connection with `connect`, which is called again for every reconnect.
+	pub async fn new<F, Fut>(connect: F) -> Result<Self, RpcError>
+	where
+		F: Fn() -> Fut + Send + Sync + 'static,
+		Fut: Future<Output = Result<C, RpcError>> + Send + 'static,
+	{
+		let connect: Connect<C> = Box::new(move || Box::pin(connect()));
+		let client = connect().await?;
+		Ok(Self {
+			connect,
+			client: RwLock::new(Arc::new(client)),
+		})
+	}
+
+	async fn current(&self) -> Arc<C> {
+		self.client.read().await.clone()
+	}
+
+	/// Replaces the `closed` connection, unless another request already did.
+	async fn reconnect(&self, closed: &Arc<C>) {
+		let mut client = self.client.write().await;
+		if !Arc::ptr_eq(&client, closed) {
+			return;
+		}
+
+		match (self.connect)().await {
+			Ok(new_client) => *client = Arc::new(new_client),
+			Err(_error) => {
+				#[cfg(feature = "tracing")]
+				tracing::warn!(error = %_error, "Failed to reconnect");
+			},
+		}
+	}
+}
+
+impl<C: RpcClientT> RpcClientT for ReconnectingClient<C> {
+	fn request_raw<'a>(
+		&'a self,
+		method: &'a str,
+		params: Option<Box<RawValue>>,
+	) -> RawRpcFuture<'a, Box<RawValue>> {
+		Box::pin(async move {
+			let client = self.current().await;
+			let result = client.request_raw(method, params).await;
+			if result.as_ref().is_err_and(is_closed) {
+				self.reconnect(&client).await;
+			}
+			result
+		})
+	}
+
+	fn subscribe_raw<'a>(
+		&'a self,
+		sub: &'a str,
+		params: Option<Box<RawValue>>,
+		unsub: &'a str,
+	) -> RawRpcFuture<'a, RawRpcSubscription> {
+		Box::pin(async move {
+			let client = self.current().await;
+			let result = client.subscribe_raw(sub, params, unsub).await;
+			if result.as_ref().is_err_and(is_closed) {
+				self.reconnect(&client).await;
+			}
+			result
+		})
+	}
+}
+
+fn is_closed(error: &RpcError) -> bool {
+	let RpcError::ClientError(error) = error else {
+		return false;
+	};
+
+	matches!(
+		error.downcast_ref::<JsonRpseeError>(),
+		Some(JsonRpseeError::RestartNeeded(_))
+	)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_client::TestClient;
+	use std::sync::atomic::{AtomicU32, Ordering};
+
+	#[tokio::test]
+	async fn reconnects_after_connection_closed() {
+		let connections = Arc::new(AtomicU32::new(0));
+		let counter = connections.clone();
+		let client = ReconnectingClient::new(move || {
+			let connection = counter.fetch_add(1, Ordering::SeqCst);
+			async move {
+				match connection {
+					0 => Ok(TestClient::new().closed()),
+					_ => Ok(TestClient::new()),
+				}
+			}
+		})
+		.await
+		.unwrap();
+
+		assert!(client.request_raw("chain_getHeader", None).await.is_err());
+		assert!(client.request_raw("chain_getHeader", None).await.is_ok());
+		assert!(client.request_raw("chain_getHeader", None).await.is_ok());
+		assert_eq!(connections.load(Ordering::SeqCst), 2);
+	}
+
+	#[tokio::test]
+	async fn keeps_connection_on_other_errors() {
+		let connections = Arc::new(AtomicU32::new(0));
+		let counter = connections.clone();
+		let client = ReconnectingClient::new(move || {
+			counter.fetch_add(1, Ordering::SeqCst);
+			async move { Ok(TestClient::new().failing(1)) }
+		})
+		.await
+		.unwrap();
+
+		assert!(client.request_raw("chain_getHeader", None).await.is_err());
+		assert!(client.request_raw("chain_getHeader", None).await.is_ok());
+		assert_eq!(connections.load(Ordering::SeqCst), 1);
+	}
+}
@@ -0,0 +1,177 @@
+use jsonrpsee::core::client::Error as JsonRpseeError;
+use serde_json::value::RawValue;
+use std::time::Duration;
+use subxt::{
+	backend::rpc::{RawRpcFuture, RawRpcSubscription, RpcClientT},
+	error::RpcError,
+};
+
+/// Methods that are never retried. Repeating them could submit the same transaction twice.
+pub const NO_RETRY_METHODS: &[&str] = &["author_submitExtrinsic"];
+
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+	/// How many times a request is attempted in total, including the first attempt.
+	pub max_attempts: u32,
+	/// Delay before the first retry. It is doubled after every failed attempt.
+	pub base_delay: Duration,
+	/// Upper bound for the delay between two attempts.
+	pub max_delay: Duration,
+	/// Methods that are sent only once regardless of the outcome.
+	pub no_retry_methods: Vec<String>,
+}
+
+impl RetryPolicy {
+	pub fn new(max_attempts: u32, base_delay: Duration, max_delay: Duration) -> Self {
+		Self {
+			max_attempts,
+			base_delay,
+			max_delay,
+			no_retry_methods: NO_RETRY_METHODS.iter().map(|m| m.to_string()).collect(),
+		}
+	}
+
+	/// Excludes `method` from being retried.
+	pub fn no_retry(mut self, method: &str) -> Self {
+		self.no_retry_methods.push(method.to_string());
+		self
+	}
+
+	fn is_retryable(&self, method: &str) -> bool {
+		!self.no_retry_methods.iter().any(|m| m == method)
+	}
+
+	fn delay(&self, attempt: u32) -> Duration {
+		let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+		self.base_delay.saturating_mul(factor).min(self.max_delay)
+	}
+}
+
+impl Default for RetryPolicy {
+	fn default() -> Self {
+		Self::new(3, Duration::from_millis(500), Duration::from_secs(5))
+	}
+}
+
+/// RPC client that retries requests failing with a transport error using exponential backoff.
+/// Errors returned by the node itself and decode errors are never retried. Subscriptions are
+/// passed through as they are.
+pub struct RetryClient<C> {
+	inner: C,
+	policy: RetryPolicy,
+}
+
+impl<C: RpcClientT> RetryClient<C> {
+	pub fn new(inner: C, policy: RetryPolicy) -> Self {
+		Self { inner, policy }
+	}
+}
+
+impl<C: RpcClientT> RpcClientT for RetryClient<C> {
+	fn request_raw<'a>(
+		&'a self,
+		method: &'a str,
+		params: Option<Box<RawValue>>,
+	) -> RawRpcFuture<'a, Box<RawValue>> {
+		Box::pin(async move {
+			if !self.policy.is_retryable(method) {
+				return self.inner.request_raw(method, params).await;
+			}
+
+			let mut attempt = 0;
+			loop {
+				attempt += 1;
+				let result = self.inner.request_raw(method, params.clone()).await;
+				match result {
+					Err(error) if attempt < self.policy.max_attempts && is_transient(&error) => {
+						#[cfg(feature = "tracing")]
+						tracing::warn!(method, attempt, %error, "Retrying RPC request");
+						tokio::time::sleep(self.policy.delay(attempt)).await;
+					},
+					result => return result,
+				}
+			}
+		})
+	}
+
+	fn subscribe_raw<'a>(
+		&'a self,
+		sub: &'a str,
+		params: Option<Box<RawValue>>,
+		unsub: &'a str,
+	) -> RawRpcFuture<'a, RawRpcSubscription> {
+		self.inner.subscribe_raw(sub, params, unsub)
+	}
+}
+
+/// A closed connection is not transient: jsonrpsee fails every request on it from then on,
+/// so retrying on the same client cannot succeed.
+pub(crate) fn is_transient(error: &RpcError) -> bool {
+	let RpcError::ClientError(error) = error else {
+		return false;
+	};
+	let Some(error) = error.downcast_ref::<JsonRpseeError>() else {
+		return false;
+	};
+
+	matches!(
+		error,
+		JsonRpseeError::Transport(_) | JsonRpseeError::RequestTimeout
+	)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_client::TestClient;
+	use std::sync::Arc;
+
+	fn policy() -> RetryPolicy {
+		RetryPolicy::new(3, Duration::from_millis(1), Duration::from_millis(5))
+	}
+
+	#[tokio::test]
+	async fn retries_transient_errors() {
+		let client = RetryClient::new(TestClient::new().failing(2), policy());
+		let result = client.request_raw("chain_getHeader", None).await;
+
+		assert!(result.is_ok());
+		assert_eq!(client.inner.calls(), 3);
+	}
+
+	#[tokio::test]
+	async fn gives_up_after_max_attempts() {
+		let client = RetryClient::new(TestClient::new().failing(5), policy());
+		let result = client.request_raw("chain_getHeader", None).await;
+
+		assert!(result.is_err());
+		assert_eq!(client.inner.calls(), 3);
+	}
+
+	#[tokio::test]
+	async fn does_not_retry_submission() {
+		let client = RetryClient::new(TestClient::new().failing(1), policy());
+		let result = client.request_raw("author_submitExtrinsic", None).await;
+
+		assert!(result.is_err());
+		assert_eq!(client.inner.calls(), 1);
+	}
+
+	#[test]
+	fn closed_connection_is_not_transient() {
+		let closed = JsonRpseeError::RestartNeeded(Arc::new(JsonRpseeError::RequestTimeout));
+		let timeout = JsonRpseeError::RequestTimeout;
+
+		assert!(!is_transient(&RpcError::ClientError(Box::new(closed))));
+		assert!(is_transient(&RpcError::ClientError(Box::new(timeout))));
+	}
+
+	#[test]
+	fn delay_is_capped() {
+		let policy = policy();
+
+		assert_eq!(policy.delay(1), Duration::from_millis(1));
+		assert_eq!(policy.delay(2), Duration::from_millis(2));
+		assert_eq!(policy.delay(10), Duration::from_millis(5));
+	}
+}
@@ -2,10 +2,17 @@ use avail_core::data_proof::ProofResponse;
 use subxt::backend::legacy::LegacyRpcMethods;
 
 use crate::avail::runtime_types::frame_system::limits::BlockLength;
-use crate::from_substrate::{FeeDetails, NodeRole, PeerInfo, RuntimeDispatchInfo, SyncState};
+use crate::from_substrate::{
+	ApplyExtrinsicResult, ExtrinsicOrHash, FeeDetails, NodeRole, PeerInfo, RuntimeDispatchInfo,
+	StorageChangeSet, SyncState,
+};
+use crate::primitives::block::grandpa::FinalityProof;
 use crate::{
-	AvailBlockDetailsRPC, AvailConfig, AvailHeader, BlockHash, BlockNumber, Cell, GDataProof, GRow,
+	AvailBlockDetailsRPC, AvailConfig, AvailHeader, BlockHash, BlockNumber, Cell, ClientError,
+	GDataProof, GRow, StorageKey, TxHash,
 };
+use codec::Decode;
+use futures::{Stream, StreamExt};
 use subxt::backend::legacy::rpc_methods::{Bytes, SystemHealth};
 use subxt::backend::rpc::RpcClient;
 use subxt::rpc_params;
@@ -13,6 +20,25 @@ use subxt::rpc_params;
 /// Arbitrary properties defined in chain spec as a JSON object
 pub type Properties = serde_json::map::Map<String, serde_json::Value>;
 
+/// Well known chain spec properties used for displaying balances and addresses.
+#[derive(Clone, Debug, PartialEq, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChainProperties {
+	pub token_symbol: Option<String>,
+	pub token_decimals: Option<u32>,
+	pub ss58_format: Option<u16>,
+}
+
+/// Block that reads without an explicit block hash are made at.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ReadMode {
+	/// The best block, which can still be reorged away.
+	#[default]
+	Best,
+	/// The latest finalized block. Costs an extra request to look up the finalized head.
+	Finalized,
+}
+
 #[derive(Clone)]
 pub struct Rpc {
 	pub client: RpcClient,
@@ -22,22 +48,33 @@ pub struct Rpc {
 	pub chain: Chain,
 	pub system: System,
 	pub payment: Payment,
+	pub state: State,
+	pub grandpa: Grandpa,
+	pub chain_spec: ChainSpec,
 }
 
 impl Rpc {
-	pub async fn new(endpoint: &str, secure: bool) -> Result<Self, Box<dyn std::error::Error>> {
+	pub async fn new(endpoint: &str, secure: bool) -> Result<Self, ClientError> {
 		let client: RpcClient = match secure {
 			true => RpcClient::from_url(endpoint).await?,
 			false => RpcClient::from_insecure_url(endpoint).await?,
 		};
+
+		Ok(Self::from_client(client))
+	}
+
+	pub fn from_client(client: RpcClient) -> Self {
 		let legacy_methods = LegacyRpcMethods::new(client.clone());
 		let kate = Kate::new(client.clone());
 		let author = Author::new(client.clone());
 		let chain: Chain = Chain::new(client.clone());
 		let system = System::new(client.clone());
 		let payment = Payment::new(client.clone());
+		let state = State::new(client.clone());
+		let grandpa = Grandpa::new(client.clone());
+		let chain_spec = ChainSpec::new(client.clone());
 
-		Ok(Self {
+		Self {
 			client,
 			legacy_methods,
 			kate,
@@ -45,10 +82,49 @@ impl Rpc {
 			chain,
 			system,
 			payment,
-		})
+			state,
+			grandpa,
+			chain_spec,
+		}
+	}
+
+	/// Makes block, header, storage and runtime API reads of `chain` and `state` that are
+	/// not given a block hash use `read_mode`.
+	pub fn with_read_mode(mut self, read_mode: ReadMode) -> Self {
+		self.chain.read_mode = read_mode;
+		self.state.read_mode = read_mode;
+		self
 	}
 }
 
+fn lowest_reorged_height(
+	known: &[(BlockNumber, BlockHash)],
+	current: &[Option<BlockHash>],
+) -> Option<BlockNumber> {
+	known
+		.iter()
+		.zip(current)
+		.filter(|((_, known), current)| **current != Some(*known))
+		.map(|((block_number, _), _)| *block_number)
+		.min()
+}
+
+/// Block hash to read at. Explicit hashes are always used as they are.
+async fn resolve_at(
+	client: &RpcClient,
+	read_mode: ReadMode,
+	at: Option<BlockHash>,
+) -> Result<Option<BlockHash>, ClientError> {
+	if at.is_some() || read_mode == ReadMode::Best {
+		return Ok(at);
+	}
+
+	let value: BlockHash = client
+		.request("chain_getFinalizedHead", rpc_params![])
+		.await?;
+	Ok(Some(value))
+}
+
 #[derive(Clone)]
 pub struct Payment {
 	client: RpcClient,
@@ -63,7 +139,7 @@ impl Payment {
 		&self,
 		extrinsic: Bytes,
 		at: Option<BlockHash>,
-	) -> Result<FeeDetails, subxt::Error> {
+	) -> Result<FeeDetails, ClientError> {
 		let value: FeeDetails = self
 			.client
 			.request("payment_queryFeeDetails", rpc_params![extrinsic, at])
@@ -75,7 +151,7 @@ impl Payment {
 		&self,
 		extrinsic: Bytes,
 		at: Option<BlockHash>,
-	) -> Result<RuntimeDispatchInfo, subxt::Error> {
+	) -> Result<RuntimeDispatchInfo, ClientError> {
 		let value: RuntimeDispatchInfo = self
 			.client
 			.request("payment_queryInfo", rpc_params![extrinsic, at])
@@ -129,6 +205,23 @@ mod tests {
 			},
 		}; */
 	}
+
+	#[test]
+	fn lowest_reorged_height_reports_first_changed_block() {
+		let a = BlockHash::repeat_byte(1);
+		let b = BlockHash::repeat_byte(2);
+		let known = [(12, a), (10, a), (11, a)];
+
+		assert_eq!(lowest_reorged_height(&known, &[Some(a); 3]), None);
+		assert_eq!(
+			lowest_reorged_height(&known, &[Some(b), Some(a), Some(b)]),
+			Some(11)
+		);
+		assert_eq!(
+			lowest_reorged_height(&known, &[None, Some(a), Some(a)]),
+			Some(12)
+		);
+	}
 }
 
 #[derive(Clone)]
@@ -141,7 +234,7 @@ impl System {
 		Self { client }
 	}
 
-	pub async fn account_next_index(&self, account: String) -> Result<u32, subxt::Error> {
+	pub async fn account_next_index(&self, account: String) -> Result<u32, ClientError> {
 		let value: u32 = self
 			.client
 			.request("system_accountNextIndex", rpc_params![account])
@@ -149,12 +242,24 @@ impl System {
 		Ok(value)
 	}
 
-	pub async fn chain(&self) -> Result<String, subxt::Error> {
+	/// Fetches the next index of every account concurrently over the same connection.
+	/// Results are returned in the same order as `accounts`.
+	pub async fn batch_account_next_index(
+		&self,
+		accounts: Vec<String>,
+	) -> Vec<Result<u32, ClientError>> {
+		let requests = accounts
+			.into_iter()
+			.map(|account| self.account_next_index(account));
+		futures::future::join_all(requests).await
+	}
+
+	pub async fn chain(&self) -> Result<String, ClientError> {
 		let value: String = self.client.request("system_chain", rpc_params![]).await?;
 		Ok(value)
 	}
 
-	pub async fn chain_type(&self) -> Result<String, subxt::Error> {
+	pub async fn chain_type(&self) -> Result<String, ClientError> {
 		let value: String = self
 			.client
 			.request("system_chainType", rpc_params![])
@@ -162,12 +267,36 @@ impl System {
 		Ok(value)
 	}
 
-	pub async fn health(&self) -> Result<SystemHealth, subxt::Error> {
+	/// Executes the extrinsic against the state of block `at`, or the best block if none is
+	/// given, without submitting it.
+	pub async fn dry_run(
+		&self,
+		extrinsic: Bytes,
+		at: Option<BlockHash>,
+	) -> Result<ApplyExtrinsicResult, ClientError> {
+		let value: Bytes = self
+			.client
+			.request("system_dryRun", rpc_params![extrinsic, at])
+			.await?;
+		let value = ApplyExtrinsicResult::decode(&mut value.0.as_slice())?;
+		Ok(value)
+	}
+
+	pub async fn health(&self) -> Result<SystemHealth, ClientError> {
 		let value: SystemHealth = self.client.request("system_health", rpc_params![]).await?;
 		Ok(value)
 	}
 
-	pub async fn local_listen_addresses(&self) -> Result<Vec<String>, subxt::Error> {
+	/// Quick liveness check. Returns `false` if the node can not be reached or is still
+	/// syncing.
+	pub async fn is_healthy(&self) -> bool {
+		match self.health().await {
+			Ok(health) => !health.is_syncing,
+			Err(_) => false,
+		}
+	}
+
+	pub async fn local_listen_addresses(&self) -> Result<Vec<String>, ClientError> {
 		let value: Vec<String> = self
 			.client
 			.request("system_localListenAddresses", rpc_params![])
@@ -175,7 +304,7 @@ impl System {
 		Ok(value)
 	}
 
-	pub async fn local_peer_id(&self) -> Result<String, subxt::Error> {
+	pub async fn local_peer_id(&self) -> Result<String, ClientError> {
 		let value: String = self
 			.client
 			.request("system_localPeerId", rpc_params![])
@@ -183,12 +312,12 @@ impl System {
 		Ok(value)
 	}
 
-	pub async fn name(&self) -> Result<String, subxt::Error> {
+	pub async fn name(&self) -> Result<String, ClientError> {
 		let value: String = self.client.request("system_name", rpc_params![]).await?;
 		Ok(value)
 	}
 
-	pub async fn node_roles(&self) -> Result<Vec<NodeRole>, subxt::Error> {
+	pub async fn node_roles(&self) -> Result<Vec<NodeRole>, ClientError> {
 		let value: Vec<NodeRole> = self
 			.client
 			.request("system_nodeRoles", rpc_params![])
@@ -196,12 +325,12 @@ impl System {
 		Ok(value)
 	}
 
-	pub async fn peers(&self) -> Result<Vec<PeerInfo>, subxt::Error> {
+	pub async fn peers(&self) -> Result<Vec<PeerInfo>, ClientError> {
 		let value: Vec<PeerInfo> = self.client.request("system_peers", rpc_params![]).await?;
 		Ok(value)
 	}
 
-	pub async fn properties(&self) -> Result<Properties, subxt::Error> {
+	pub async fn properties(&self) -> Result<Properties, ClientError> {
 		let value: Properties = self
 			.client
 			.request("system_properties", rpc_params![])
@@ -209,7 +338,7 @@ impl System {
 		Ok(value)
 	}
 
-	pub async fn sync_state(&self) -> Result<SyncState, subxt::Error> {
+	pub async fn sync_state(&self) -> Result<SyncState, ClientError> {
 		let value: SyncState = self
 			.client
 			.request("system_syncState", rpc_params![])
@@ -217,7 +346,7 @@ impl System {
 		Ok(value)
 	}
 
-	pub async fn version(&self) -> Result<String, subxt::Error> {
+	pub async fn version(&self) -> Result<String, ClientError> {
 		let value: String = self.client.request("system_version", rpc_params![]).await?;
 		Ok(value)
 	}
@@ -226,17 +355,22 @@ impl System {
 #[derive(Clone)]
 pub struct Chain {
 	client: RpcClient,
+	read_mode: ReadMode,
 }
 
 impl Chain {
 	pub fn new(client: RpcClient) -> Self {
-		Self { client }
+		Self {
+			client,
+			read_mode: ReadMode::Best,
+		}
 	}
 
 	pub async fn get_block(
 		&self,
 		at: Option<BlockHash>,
-	) -> Result<AvailBlockDetailsRPC, subxt::Error> {
+	) -> Result<AvailBlockDetailsRPC, ClientError> {
+		let at = resolve_at(&self.client, self.read_mode, at).await?;
 		let value: AvailBlockDetailsRPC = self
 			.client
 			.request("chain_getBlock", rpc_params![at])
@@ -247,7 +381,7 @@ impl Chain {
 	pub async fn get_block_hash(
 		&self,
 		block_number: Option<BlockNumber>,
-	) -> Result<BlockHash, subxt::Error> {
+	) -> Result<BlockHash, ClientError> {
 		let value: BlockHash = self
 			.client
 			.request("chain_getBlockHash", rpc_params![block_number])
@@ -255,7 +389,20 @@ impl Chain {
 		Ok(value)
 	}
 
-	pub async fn get_finalized_head(&self) -> Result<BlockHash, subxt::Error> {
+	/// Hash of the block at `block_number`. `None` if the chain has no block at that height
+	/// yet.
+	pub async fn try_get_block_hash(
+		&self,
+		block_number: BlockNumber,
+	) -> Result<Option<BlockHash>, ClientError> {
+		let value: Option<BlockHash> = self
+			.client
+			.request("chain_getBlockHash", rpc_params![block_number])
+			.await?;
+		Ok(value)
+	}
+
+	pub async fn get_finalized_head(&self) -> Result<BlockHash, ClientError> {
 		let value: BlockHash = self
 			.client
 			.request("chain_getFinalizedHead", rpc_params![])
@@ -263,13 +410,237 @@ impl Chain {
 		Ok(value)
 	}
 
-	pub async fn get_header(&self, at: Option<BlockHash>) -> Result<AvailHeader, subxt::Error> {
+	/// Number of the best block, fetched with a single request regardless of the read mode.
+	pub async fn best_block_number(&self) -> Result<BlockNumber, ClientError> {
+		let header: AvailHeader = self
+			.client
+			.request("chain_getHeader", rpc_params![])
+			.await?;
+		Ok(header.number)
+	}
+
+	pub async fn finalized_block_number(&self) -> Result<BlockNumber, ClientError> {
+		let block_hash = self.get_finalized_head().await?;
+		let header = self.get_header(Some(block_hash)).await?;
+		Ok(header.number)
+	}
+
+	pub async fn get_header(&self, at: Option<BlockHash>) -> Result<AvailHeader, ClientError> {
+		let at = resolve_at(&self.client, self.read_mode, at).await?;
 		let value: AvailHeader = self
 			.client
 			.request("chain_getHeader", rpc_params![at])
 			.await?;
 		Ok(value)
 	}
+
+	/// Looks up the current hash of every height in `known` and returns the lowest height
+	/// whose hash changed, e.g. because of a reorg. Heights without a block any more count as
+	/// changed. Returns `None` if every hash still matches.
+	pub async fn detect_reorg(
+		&self,
+		known: &[(BlockNumber, BlockHash)],
+	) -> Result<Option<BlockNumber>, ClientError> {
+		let requests = known
+			.iter()
+			.map(|(block_number, _)| self.try_get_block_hash(*block_number));
+		let current = futures::future::try_join_all(requests).await?;
+
+		Ok(lowest_reorged_height(known, &current))
+	}
+
+	/// Fetches the headers of all `hashes` concurrently over the same connection. Results are
+	/// returned in the same order as `hashes`.
+	pub async fn get_headers(&self, hashes: &[BlockHash]) -> Vec<Result<AvailHeader, ClientError>> {
+		let requests = hashes.iter().map(|hash| self.get_header(Some(*hash)));
+		futures::future::join_all(requests).await
+	}
+
+	/// Fetches the blocks `from..=to` with at most `concurrency` blocks being fetched at once.
+	/// Blocks are yielded in ascending order, regardless of which fetch completes first. The
+	/// stream ends early at the first height the chain has no block at yet.
+	pub fn get_blocks_range(
+		&self,
+		from: BlockNumber,
+		to: BlockNumber,
+		concurrency: usize,
+	) -> impl Stream<Item = Result<AvailBlockDetailsRPC, ClientError>> {
+		let chain = self.clone();
+		futures::stream::iter(from..=to)
+			.map(move |block_number| {
+				let chain = chain.clone();
+				async move {
+					let Some(block_hash) = chain.try_get_block_hash(block_number).await? else {
+						return Ok(None);
+					};
+					chain.get_block(Some(block_hash)).await.map(Some)
+				}
+			})
+			.buffered(concurrency.max(1))
+			.take_while(|block| std::future::ready(!matches!(block, Ok(None))))
+			.filter_map(|block| std::future::ready(block.transpose()))
+	}
+
+	/// Yields the header of every newly finalized block. The node is unsubscribed once the
+	/// stream is dropped.
+	pub async fn subscribe_finalized_heads(
+		&self,
+	) -> Result<impl Stream<Item = Result<AvailHeader, ClientError>>, ClientError> {
+		let subscription = self
+			.client
+			.subscribe::<AvailHeader>(
+				"chain_subscribeFinalizedHeads",
+				rpc_params![],
+				"chain_unsubscribeFinalizedHeads",
+			)
+			.await?;
+		Ok(subscription.map(|header| header.map_err(ClientError::from)))
+	}
+
+	/// Yields the header of every new best block. The node is unsubscribed once the stream
+	/// is dropped.
+	pub async fn subscribe_new_heads(
+		&self,
+	) -> Result<impl Stream<Item = Result<AvailHeader, ClientError>>, ClientError> {
+		let subscription = self
+			.client
+			.subscribe::<AvailHeader>(
+				"chain_subscribeNewHeads",
+				rpc_params![],
+				"chain_unsubscribeNewHeads",
+			)
+			.await?;
+		Ok(subscription.map(|header| header.map_err(ClientError::from)))
+	}
+}
+
+#[derive(Clone)]
+pub struct State {
+	client: RpcClient,
+	read_mode: ReadMode,
+}
+
+impl State {
+	pub fn new(client: RpcClient) -> Self {
+		Self {
+			client,
+			read_mode: ReadMode::Best,
+		}
+	}
+
+	/// Calls the runtime API `method`, e.g. `AccountNonceApi_account_nonce`, with SCALE encoded
+	/// `data` as arguments and returns the SCALE encoded result.
+	pub async fn call(
+		&self,
+		method: &str,
+		data: Bytes,
+		at: Option<BlockHash>,
+	) -> Result<Bytes, ClientError> {
+		let at = resolve_at(&self.client, self.read_mode, at).await?;
+		let value: Bytes = self
+			.client
+			.request("state_call", rpc_params![method, data, at])
+			.await?;
+		Ok(value)
+	}
+
+	/// Same as [`State::call`] but the result is SCALE decoded into `T`.
+	pub async fn call_decoded<T: Decode>(
+		&self,
+		method: &str,
+		data: Bytes,
+		at: Option<BlockHash>,
+	) -> Result<T, ClientError> {
+		let value = self.call(method, data, at).await?;
+		let value = T::decode(&mut value.0.as_slice())?;
+		Ok(value)
+	}
+
+	pub async fn get_storage(
+		&self,
+		key: StorageKey,
+		at: Option<BlockHash>,
+	) -> Result<Option<Bytes>, ClientError> {
+		let at = resolve_at(&self.client, self.read_mode, at).await?;
+		let value: Option<Bytes> = self
+			.client
+			.request("state_getStorage", rpc_params![key, at])
+			.await?;
+		Ok(value)
+	}
+
+	/// Returns up to `count` keys starting with `prefix`, after `start_key` if given. Pass the
+	/// last key of a page as `start_key` to fetch the next one.
+	pub async fn get_keys_paged(
+		&self,
+		prefix: StorageKey,
+		count: u32,
+		start_key: Option<StorageKey>,
+		at: Option<BlockHash>,
+	) -> Result<Vec<StorageKey>, ClientError> {
+		let at = resolve_at(&self.client, self.read_mode, at).await?;
+		let value: Vec<StorageKey> = self
+			.client
+			.request(
+				"state_getKeysPaged",
+				rpc_params![prefix, count, start_key, at],
+			)
+			.await?;
+		Ok(value)
+	}
+
+	/// Reads all `keys` at block `at` in a single request. Values are returned in the order
+	/// of `keys`; keys without a value are paired with `None`.
+	pub async fn query_storage_at(
+		&self,
+		keys: Vec<StorageKey>,
+		at: Option<BlockHash>,
+	) -> Result<Vec<(StorageKey, Option<Bytes>)>, ClientError> {
+		let at = resolve_at(&self.client, self.read_mode, at).await?;
+		let value: Vec<StorageChangeSet> = self
+			.client
+			.request("state_queryStorageAt", rpc_params![keys, at])
+			.await?;
+		let value = value.into_iter().flat_map(|set| set.changes).collect();
+		Ok(value)
+	}
+
+	/// Same as [`State::get_storage`] but the value is SCALE decoded into `T`.
+	pub async fn get_storage_decoded<T: Decode>(
+		&self,
+		key: StorageKey,
+		at: Option<BlockHash>,
+	) -> Result<Option<T>, ClientError> {
+		let Some(value) = self.get_storage(key, at).await? else {
+			return Ok(None);
+		};
+		let value = T::decode(&mut value.0.as_slice())?;
+		Ok(Some(value))
+	}
+
+	/// Yields the new values of `keys` for every block in which at least one of them changed.
+	/// The first item contains the current values. The node is unsubscribed once the stream
+	/// is dropped.
+	pub async fn subscribe_storage(
+		&self,
+		keys: Vec<StorageKey>,
+	) -> Result<
+		impl Stream<Item = Result<(BlockHash, Vec<(StorageKey, Option<Bytes>)>), ClientError>>,
+		ClientError,
+	> {
+		let subscription = self
+			.client
+			.subscribe::<StorageChangeSet>(
+				"state_subscribeStorage",
+				rpc_params![keys],
+				"state_unsubscribeStorage",
+			)
+			.await?;
+		Ok(subscription.map(|set| {
+			set.map(|set| (set.block, set.changes))
+				.map_err(ClientError::from)
+		}))
+	}
 }
 
 #[derive(Clone)]
@@ -282,7 +653,31 @@ impl Author {
 		Self { client }
 	}
 
-	pub async fn rotate_keys(&self) -> Result<Vec<u8>, subxt::Error> {
+	pub async fn pending_extrinsics(&self) -> Result<Vec<Bytes>, ClientError> {
+		let value: Vec<Bytes> = self
+			.client
+			.request("author_pendingExtrinsics", rpc_params![])
+			.await?;
+		Ok(value)
+	}
+
+	/// Removes transactions from the node's pool, returning the hashes of the ones that were
+	/// removed. Dependent transactions are removed as well. Requires the node to expose unsafe
+	/// RPC methods.
+	pub async fn remove_extrinsic(
+		&self,
+		tx_hashes: Vec<TxHash>,
+	) -> Result<Vec<TxHash>, ClientError> {
+		let tx_hashes: Vec<ExtrinsicOrHash> =
+			tx_hashes.into_iter().map(ExtrinsicOrHash::Hash).collect();
+		let value: Vec<TxHash> = self
+			.client
+			.request("author_removeExtrinsic", rpc_params![tx_hashes])
+			.await?;
+		Ok(value)
+	}
+
+	pub async fn rotate_keys(&self) -> Result<Vec<u8>, ClientError> {
 		let bytes: Bytes = self
 			.client
 			.request("author_rotateKeys", rpc_params![])
@@ -301,7 +696,7 @@ impl Kate {
 		Self { client }
 	}
 
-	pub async fn block_length(&self, at: Option<BlockHash>) -> Result<BlockLength, subxt::Error> {
+	pub async fn block_length(&self, at: Option<BlockHash>) -> Result<BlockLength, ClientError> {
 		let result: BlockLength = self
 			.client
 			.request("kate_blockLength", rpc_params![at])
@@ -313,7 +708,7 @@ impl Kate {
 		&self,
 		transaction_index: u32,
 		at: Option<BlockHash>,
-	) -> Result<ProofResponse, subxt::Error> {
+	) -> Result<ProofResponse, ClientError> {
 		let result: ProofResponse = self
 			.client
 			.request("kate_queryDataProof", rpc_params![transaction_index, at])
@@ -325,7 +720,7 @@ impl Kate {
 		&self,
 		cells: Vec<Cell>,
 		at: Option<BlockHash>,
-	) -> Result<Vec<GDataProof>, subxt::Error> {
+	) -> Result<Vec<GDataProof>, ClientError> {
 		let result: Vec<GDataProof> = self
 			.client
 			.request("kate_queryProof", rpc_params![cells, at])
@@ -337,7 +732,7 @@ impl Kate {
 		&self,
 		rows: Vec<u32>,
 		at: Option<BlockHash>,
-	) -> Result<Vec<GRow>, subxt::Error> {
+	) -> Result<Vec<GRow>, ClientError> {
 		let result: Vec<GRow> = self
 			.client
 			.request("kate_queryRows", rpc_params![rows, at])
@@ -345,3 +740,65 @@ impl Kate {
 		Ok(result)
 	}
 }
+
+#[derive(Clone)]
+pub struct Grandpa {
+	client: RpcClient,
+}
+
+impl Grandpa {
+	pub fn new(client: RpcClient) -> Self {
+		Self { client }
+	}
+
+	/// Proof of finality for the given block. `None` if the block is not finalized yet.
+	pub async fn prove_finality(
+		&self,
+		block_number: BlockNumber,
+	) -> Result<Option<FinalityProof>, ClientError> {
+		let value: Option<Bytes> = self
+			.client
+			.request("grandpa_proveFinality", rpc_params![block_number])
+			.await?;
+		let Some(value) = value else {
+			return Ok(None);
+		};
+		let value = FinalityProof::decode(&mut value.0.as_slice())?;
+		Ok(Some(value))
+	}
+}
+
+#[derive(Clone)]
+pub struct ChainSpec {
+	client: RpcClient,
+}
+
+impl ChainSpec {
+	pub fn new(client: RpcClient) -> Self {
+		Self { client }
+	}
+
+	pub async fn chain_name(&self) -> Result<String, ClientError> {
+		let value: String = self
+			.client
+			.request("chainSpec_v1_chainName", rpc_params![])
+			.await?;
+		Ok(value)
+	}
+
+	pub async fn genesis_hash(&self) -> Result<BlockHash, ClientError> {
+		let value: BlockHash = self
+			.client
+			.request("chainSpec_v1_genesisHash", rpc_params![])
+			.await?;
+		Ok(value)
+	}
+
+	pub async fn properties(&self) -> Result<ChainProperties, ClientError> {
+		let value: ChainProperties = self
+			.client
+			.request("chainSpec_v1_properties", rpc_params![])
+			.await?;
+		Ok(value)
+	}
+}
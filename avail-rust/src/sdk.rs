@@ -1,4 +1,29 @@
-use crate::{rpcs::Rpc, transactions::Transactions, utils::Util, Api};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::{
+	metadata_cache, ConcurrencyLimit, FailoverClient, FailoverPolicy, LimitClient,
+	ReconnectingClient, RetryClient, RetryPolicy,
+};
+use crate::{
+	rpcs::Rpc, transactions::Transactions, utils::Util, utils_raw::is_runtime_newer, Api,
+	AvailConfig, BlockHash, ReadMode,
+};
+use futures::{Stream, StreamExt};
+#[cfg(not(target_arch = "wasm32"))]
+use http::{HeaderMap, HeaderName, HeaderValue};
+#[cfg(not(target_arch = "wasm32"))]
+use jsonrpsee::ws_client::{WsClient, WsClientBuilder};
+#[cfg(not(target_arch = "wasm32"))]
+use std::{
+	path::{Path, PathBuf},
+	time::Duration,
+};
+use subxt::backend::legacy::LegacyRpcMethods;
+use subxt::client::RuntimeVersion;
+#[cfg(not(target_arch = "wasm32"))]
+use subxt::{
+	backend::rpc::{RpcClient, RpcClientT},
+	error::RpcError,
+};
 
 #[derive(Clone)]
 pub struct SDK {
@@ -6,6 +31,7 @@ pub struct SDK {
 	pub tx: Transactions,
 	pub util: Util,
 	pub rpc: Rpc,
+	endpoints: Vec<String>,
 }
 
 impl SDK {
@@ -15,9 +41,10 @@ impl SDK {
 
 		Ok(SDK {
 			tx: Transactions::new(api.clone(), rpc.clone()),
-			util: Util::new(api.clone()),
+			util: Util::new(api.clone(), rpc.clone()),
 			rpc,
 			api,
+			endpoints: vec![endpoint.to_string()],
 		})
 	}
 
@@ -27,11 +54,423 @@ impl SDK {
 
 		Ok(SDK {
 			tx: Transactions::new(api.clone(), rpc.clone()),
-			util: Util::new(api.clone()),
+			util: Util::new(api.clone(), rpc.clone()),
 			rpc,
 			api,
+			endpoints: vec![endpoint.to_string()],
 		})
 	}
+
+	/// Same as [`SDK::new`] but the underlying connection is configured with `options`.
+	/// A single connection is shared between `api` and `rpc`, and it is reopened when it
+	/// closes, see [`ReconnectingClient`].
+	#[cfg(not(target_arch = "wasm32"))]
+	pub async fn with_options(
+		endpoint: &str,
+		options: ClientOptions,
+	) -> Result<Self, Box<dyn std::error::Error>> {
+		let client = ws_client(endpoint, &options).await?;
+		Self::from_rpc_client(client, vec![endpoint.to_string()], options).await
+	}
+
+	/// Same as [`SDK::with_options`] but requests are spread over several endpoints, see
+	/// [`FailoverClient`]. Endpoints that can not be reached are skipped; an error is returned
+	/// only if none of them can be.
+	#[cfg(not(target_arch = "wasm32"))]
+	pub async fn with_endpoints(
+		endpoints: &[&str],
+		policy: FailoverPolicy,
+		options: ClientOptions,
+	) -> Result<Self, Box<dyn std::error::Error>> {
+		let mut clients = Vec::with_capacity(endpoints.len());
+		let mut connected = Vec::with_capacity(endpoints.len());
+		let mut last_error = None;
+		for endpoint in endpoints {
+			match ws_client(endpoint, &options).await {
+				Ok(client) => {
+					clients.push(client);
+					connected.push(endpoint.to_string());
+				},
+				Err(error) => last_error = Some(error),
+			}
+		}
+
+		if clients.is_empty() {
+			return Err(last_error.unwrap_or_else(|| "No endpoints given".into()));
+		}
+
+		let client = FailoverClient::new(clients, policy);
+		Self::from_rpc_client(client, connected, options).await
+	}
+
+	/// Same as [`SDK::with_options`] but requests are sent through `transport`, e.g. a
+	/// message bus instead of a WebSocket. `name` is what [`SDK::endpoint`] returns.
+	#[cfg(not(target_arch = "wasm32"))]
+	pub async fn with_transport<C: RpcClientT>(
+		transport: C,
+		name: &str,
+		options: ClientOptions,
+	) -> Result<Self, Box<dyn std::error::Error>> {
+		Self::from_rpc_client(transport, vec![name.to_string()], options).await
+	}
+
+	#[cfg(not(target_arch = "wasm32"))]
+	async fn from_rpc_client<C: RpcClientT>(
+		client: C,
+		endpoints: Vec<String>,
+		options: ClientOptions,
+	) -> Result<Self, Box<dyn std::error::Error>> {
+		if let (Some(expected), Some(genesis_hash)) =
+			(options.expected_genesis, options.genesis_override)
+		{
+			if expected != genesis_hash {
+				return Err(std::format!(
+					"Genesis override conflicts with the expected genesis. Expected: {:?}, Override: {:?}",
+					expected,
+					genesis_hash
+				)
+				.into());
+			}
+		}
+
+		let client = match (options.retry, options.concurrency_limit) {
+			(Some(policy), Some(limit)) => {
+				rpc_client(LimitClient::new(RetryClient::new(client, policy), limit))
+			},
+			(Some(policy), None) => rpc_client(RetryClient::new(client, policy)),
+			(None, Some(limit)) => rpc_client(LimitClient::new(client, limit)),
+			(None, None) => rpc_client(client),
+		};
+
+		let api = match &options.metadata_cache {
+			Some(dir) => api_with_metadata_cache(client.clone(), dir).await?,
+			None => Api::from_rpc_client(client.clone()).await?,
+		};
+		if let Some(expected) = options.expected_genesis {
+			let actual = api.genesis_hash();
+			if actual != expected {
+				return Err(std::format!(
+					"Genesis hash mismatch. Expected: {:?}, Actual: {:?}",
+					expected,
+					actual
+				)
+				.into());
+			}
+		}
+		let api = match options.genesis_override {
+			Some(genesis_hash) => Api::from_rpc_client_with(
+				genesis_hash,
+				api.runtime_version(),
+				api.metadata(),
+				client.clone(),
+			)?,
+			None => api,
+		};
+		let rpc = Rpc::from_client(client);
+
+		Ok(SDK {
+			tx: Transactions::new(api.clone(), rpc.clone()),
+			util: Util::new(api.clone(), rpc.clone()),
+			rpc,
+			api,
+			endpoints,
+		})
+	}
+
+	/// Makes `rpc` reads that are not given a block hash use `read_mode`, e.g.
+	/// [`ReadMode::Finalized`] so no data is read from blocks that can still be reorged away.
+	pub fn with_default_read_mode(mut self, read_mode: ReadMode) -> Self {
+		self.rpc = self.rpc.with_read_mode(read_mode);
+		self.tx = Transactions::new(self.api.clone(), self.rpc.clone());
+		self
+	}
+
+	/// The endpoint the SDK is connected to. With [`SDK::with_endpoints`] this is the first
+	/// endpoint that could be reached.
+	pub fn endpoint(&self) -> &str {
+		&self.endpoints[0]
+	}
+
+	/// All endpoints the SDK is connected to.
+	pub fn endpoints(&self) -> &[String] {
+		&self.endpoints
+	}
+
+	/// Genesis hash, runtime version and metadata are fetched once on connection and
+	/// cached by `api`. Call this after a runtime upgrade to reload them from the node.
+	pub async fn refresh_runtime_version(&self) -> Result<(), subxt::Error> {
+		let backend = self.api.backend();
+		let block_hash = backend.latest_finalized_block_ref().await?.hash();
+		let runtime_version = runtime_version_at(&self.rpc.legacy_methods, block_hash).await?;
+		let metadata = match backend.metadata_at_version(15, block_hash).await {
+			Ok(metadata) => metadata,
+			Err(_) => backend.legacy_metadata(block_hash).await?,
+		};
+
+		self.api.set_runtime_version(runtime_version);
+		self.api.set_metadata(metadata);
+		Ok(())
+	}
+
+	/// Writes the metadata of the current runtime to `path`, together with the genesis hash
+	/// and spec version it belongs to.
+	#[cfg(not(target_arch = "wasm32"))]
+	pub async fn save_metadata_to(
+		&self,
+		path: impl AsRef<Path>,
+	) -> Result<(), Box<dyn std::error::Error>> {
+		let backend = self.api.backend();
+		let block_hash = backend.latest_finalized_block_ref().await?.hash();
+		let runtime_version = runtime_version_at(&self.rpc.legacy_methods, block_hash).await?;
+		let metadata = metadata_cache::fetch_raw_metadata(backend, block_hash).await?;
+
+		metadata_cache::save(
+			path.as_ref(),
+			self.api.genesis_hash(),
+			&runtime_version,
+			metadata,
+		)?;
+		Ok(())
+	}
+
+	/// Replaces the metadata used by `api` with the one saved by [`SDK::save_metadata_to`].
+	/// Fails if the file was saved for another chain or if the node has moved on to a
+	/// different runtime since.
+	#[cfg(not(target_arch = "wasm32"))]
+	pub async fn load_metadata_from(
+		&self,
+		path: impl AsRef<Path>,
+	) -> Result<(), Box<dyn std::error::Error>> {
+		let runtime_version = self.api.backend().current_runtime_version().await?;
+		let metadata = metadata_cache::load(
+			path.as_ref(),
+			self.api.genesis_hash(),
+			runtime_version.spec_version,
+		)?;
+
+		self.api.set_runtime_version(runtime_version);
+		self.api.set_metadata(metadata);
+		Ok(())
+	}
+
+	/// Yields the new runtime version whenever the node upgrades to a newer runtime, see
+	/// [`crate::utils_raw::is_runtime_newer`]. Call [`SDK::refresh_runtime_version`] once an
+	/// upgrade is seen to keep signing with the right metadata.
+	pub async fn watch_runtime_upgrades(
+		&self,
+	) -> Result<impl Stream<Item = Result<RuntimeVersion, subxt::Error>>, subxt::Error> {
+		let mut current = self.api.runtime_version();
+		let versions = self.api.backend().stream_runtime_version().await?;
+
+		Ok(versions.filter_map(move |version| {
+			let item = match version {
+				Ok(version) if is_runtime_newer(&version, &current) => {
+					current = version.clone();
+					Some(Ok(version))
+				},
+				Ok(_) => None,
+				Err(error) => Some(Err(error)),
+			};
+			futures::future::ready(item)
+		}))
+	}
+}
+
+/// Only the endpoints are printed. Connection headers are never part of the output.
+impl std::fmt::Debug for SDK {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.debug_struct("SDK")
+			.field("endpoints", &self.endpoints)
+			.finish_non_exhaustive()
+	}
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Debug, Clone)]
+pub struct ClientOptions {
+	/// How long to wait for a response before the request fails.
+	pub request_timeout: Duration,
+	/// Maximum number of requests that can be in flight at the same time. All clones of the
+	/// SDK share one connection, so this limit applies to all of them together.
+	pub max_concurrent_requests: usize,
+	/// Maximum size of a single response in bytes.
+	pub max_response_size: u32,
+	/// Retry requests that fail because of a transport error. Disabled by default.
+	pub retry: Option<RetryPolicy>,
+	/// Requests beyond this limit wait for a slot instead of failing like they do once
+	/// `max_concurrent_requests` is reached. Keep a clone to change the limit or read the
+	/// number of requests in flight later on. Disabled by default.
+	pub concurrency_limit: Option<ConcurrencyLimit>,
+	/// Refuse to connect if the node's genesis hash differs. Guards against signing
+	/// transactions for the wrong network.
+	pub expected_genesis: Option<BlockHash>,
+	/// Genesis hash used when signing instead of the one reported by the node, for private
+	/// forks that need a fixed genesis. `expected_genesis` is still checked against the node,
+	/// and connecting fails if both are set to different hashes.
+	pub genesis_override: Option<BlockHash>,
+	/// Headers sent with the connection request, e.g. API keys required by hosted providers.
+	pub headers: HeaderMap,
+	/// Directory where metadata is cached, one file per genesis hash and spec version.
+	/// Metadata is downloaded only if no file exists for the runtime the node is running.
+	pub metadata_cache: Option<PathBuf>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl ClientOptions {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	pub fn request_timeout(mut self, value: Duration) -> Self {
+		self.request_timeout = value;
+		self
+	}
+
+	pub fn max_concurrent_requests(mut self, value: usize) -> Self {
+		self.max_concurrent_requests = value;
+		self
+	}
+
+	pub fn max_response_size(mut self, value: u32) -> Self {
+		self.max_response_size = value;
+		self
+	}
+
+	pub fn retry(mut self, value: RetryPolicy) -> Self {
+		self.retry = Some(value);
+		self
+	}
+
+	pub fn concurrency_limit(mut self, value: ConcurrencyLimit) -> Self {
+		self.concurrency_limit = Some(value);
+		self
+	}
+
+	pub fn expected_genesis(mut self, value: BlockHash) -> Self {
+		self.expected_genesis = Some(value);
+		self
+	}
+
+	pub fn genesis_override(mut self, value: BlockHash) -> Self {
+		self.genesis_override = Some(value);
+		self
+	}
+
+	pub fn metadata_cache(mut self, dir: impl Into<PathBuf>) -> Self {
+		self.metadata_cache = Some(dir.into());
+		self
+	}
+
+	/// Adds a header to the connection request. Fails if `name` or `value` is not a valid
+	/// HTTP header name or value. Values are marked as sensitive so they are not printed by
+	/// `Debug`.
+	pub fn header(mut self, name: &str, value: &str) -> Result<Self, String> {
+		let name = HeaderName::from_bytes(name.as_bytes()).map_err(|e| e.to_string())?;
+		let mut value = HeaderValue::from_str(value).map_err(|e| e.to_string())?;
+		value.set_sensitive(true);
+		self.headers.insert(name, value);
+		Ok(self)
+	}
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Default for ClientOptions {
+	fn default() -> Self {
+		Self {
+			request_timeout: Duration::from_secs(60),
+			max_concurrent_requests: 256,
+			max_response_size: u32::MAX,
+			retry: None,
+			concurrency_limit: None,
+			expected_genesis: None,
+			genesis_override: None,
+			headers: HeaderMap::new(),
+			metadata_cache: None,
+		}
+	}
+}
+
+/// WebSocket connection to `endpoint` that is reopened when it closes, see
+/// [`ReconnectingClient`].
+#[cfg(not(target_arch = "wasm32"))]
+async fn ws_client(
+	endpoint: &str,
+	options: &ClientOptions,
+) -> Result<ReconnectingClient<WsClient>, Box<dyn std::error::Error>> {
+	subxt::utils::validate_url_is_secure(endpoint)?;
+	let endpoint = endpoint.to_string();
+	let options = options.clone();
+	let client = ReconnectingClient::new(move || {
+		let endpoint = endpoint.clone();
+		let options = options.clone();
+		async move {
+			WsClientBuilder::default()
+				.request_timeout(options.request_timeout)
+				.max_concurrent_requests(options.max_concurrent_requests)
+				.max_response_size(options.max_response_size)
+				.set_headers(options.headers)
+				.build(endpoint)
+				.await
+				.map_err(|e| RpcError::ClientError(Box::new(e)))
+		}
+	})
+	.await?;
+	Ok(client)
+}
+
+/// Connects using the metadata cached in `dir` for the runtime the node is running. On a
+/// cache miss the metadata is downloaded and stored for the next start. Failing to write the
+/// cache does not fail the connection.
+#[cfg(not(target_arch = "wasm32"))]
+async fn api_with_metadata_cache(
+	client: RpcClient,
+	dir: &Path,
+) -> Result<Api, Box<dyn std::error::Error>> {
+	let methods = LegacyRpcMethods::<AvailConfig>::new(client.clone());
+	let genesis_hash = methods.genesis_hash().await?;
+	let block_hash = methods.chain_get_finalized_head().await?;
+	let runtime_version = runtime_version_at(&methods, block_hash).await?;
+	let path = metadata_cache::cache_path(dir, genesis_hash, runtime_version.spec_version);
+
+	if let Ok(metadata) = metadata_cache::load(&path, genesis_hash, runtime_version.spec_version) {
+		return Ok(Api::from_rpc_client_with(
+			genesis_hash,
+			runtime_version,
+			metadata,
+			client,
+		)?);
+	}
+
+	let api = Api::from_rpc_client(client).await?;
+	let backend = api.backend();
+	if let Ok(metadata) = metadata_cache::fetch_raw_metadata(backend, block_hash).await {
+		let _ = metadata_cache::save(&path, genesis_hash, &runtime_version, metadata);
+	}
+
+	Ok(api)
+}
+
+/// Runtime version at `block_hash`, so that it matches the metadata fetched at that block.
+/// The backend only reports the version of the best block.
+async fn runtime_version_at(
+	methods: &LegacyRpcMethods<AvailConfig>,
+	block_hash: BlockHash,
+) -> Result<RuntimeVersion, subxt::Error> {
+	let version = methods.state_get_runtime_version(Some(block_hash)).await?;
+	Ok(RuntimeVersion {
+		spec_version: version.spec_version,
+		transaction_version: version.transaction_version,
+	})
+}
+
+#[cfg(all(feature = "tracing", not(target_arch = "wasm32")))]
+fn rpc_client<C: RpcClientT>(client: C) -> RpcClient {
+	RpcClient::new(crate::TracingClient::new(client))
+}
+
+#[cfg(all(not(feature = "tracing"), not(target_arch = "wasm32")))]
+fn rpc_client<C: RpcClientT>(client: C) -> RpcClient {
+	RpcClient::new(client)
 }
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
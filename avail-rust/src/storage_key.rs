@@ -0,0 +1,130 @@
+//! Raw storage keys for reads through `sdk.rpc.state`, built the same way the runtime builds
+//! them: `twox128(pallet) ++ twox128(item)` followed by every map key hashed with the
+//! hasher declared in the metadata.
+//!
+//! Typed reads made through `api`, such as [`crate::utils_raw::fetch_balance`] or
+//! `api.events()`, keep using subxt's storage addresses, which also decode the value.
+
+use crate::StorageKey;
+use codec::Encode;
+use sp_core::hashing::{blake2_128, blake2_256, twox_128, twox_256, twox_64};
+use subxt::{
+	metadata::types::{StorageEntryType, StorageHasher},
+	Metadata,
+};
+
+/// Key of a storage value, e.g. `System::Number`.
+pub fn plain(pallet: &str, item: &str) -> StorageKey {
+	StorageKey::from(prefix(pallet, item))
+}
+
+/// Key of an entry of a storage map, e.g. `System::Account`. `key` is SCALE encoded and
+/// hashed with the hasher `metadata` declares for the map.
+pub fn map(
+	metadata: &Metadata,
+	pallet: &str,
+	item: &str,
+	key: &impl Encode,
+) -> Result<StorageKey, String> {
+	build(metadata, pallet, item, &[key.encode()])
+}
+
+/// Key of an entry of a storage double map, e.g. `Staking::ErasStakers`.
+pub fn double_map(
+	metadata: &Metadata,
+	pallet: &str,
+	item: &str,
+	key1: &impl Encode,
+	key2: &impl Encode,
+) -> Result<StorageKey, String> {
+	build(metadata, pallet, item, &[key1.encode(), key2.encode()])
+}
+
+fn build(
+	metadata: &Metadata,
+	pallet: &str,
+	item: &str,
+	keys: &[Vec<u8>],
+) -> Result<StorageKey, String> {
+	let hashers = hashers(metadata, pallet, item)?;
+	if hashers.len() != keys.len() {
+		return Err(std::format!(
+			"{}::{} has {} keys. Given: {}",
+			pallet,
+			item,
+			hashers.len(),
+			keys.len()
+		));
+	}
+
+	Ok(StorageKey::from(with_keys(pallet, item, &hashers, keys)))
+}
+
+fn hashers(metadata: &Metadata, pallet: &str, item: &str) -> Result<Vec<StorageHasher>, String> {
+	let entry = metadata
+		.pallet_by_name(pallet)
+		.and_then(|p| p.storage())
+		.and_then(|s| s.entry_by_name(item))
+		.ok_or_else(|| std::format!("Storage {}::{} not found in metadata", pallet, item))?;
+
+	match entry.entry_type() {
+		StorageEntryType::Plain(_) => Ok(Vec::new()),
+		StorageEntryType::Map { hashers, .. } => Ok(hashers.clone()),
+	}
+}
+
+fn prefix(pallet: &str, item: &str) -> Vec<u8> {
+	[twox_128(pallet.as_bytes()), twox_128(item.as_bytes())].concat()
+}
+
+fn with_keys(pallet: &str, item: &str, hashers: &[StorageHasher], keys: &[Vec<u8>]) -> Vec<u8> {
+	let mut key = prefix(pallet, item);
+	for (hasher, value) in hashers.iter().zip(keys) {
+		key.extend(hash(hasher, value));
+	}
+	key
+}
+
+fn hash(hasher: &StorageHasher, value: &[u8]) -> Vec<u8> {
+	match hasher {
+		StorageHasher::Blake2_128 => blake2_128(value).to_vec(),
+		StorageHasher::Blake2_256 => blake2_256(value).to_vec(),
+		StorageHasher::Blake2_128Concat => [&blake2_128(value)[..], value].concat(),
+		StorageHasher::Twox128 => twox_128(value).to_vec(),
+		StorageHasher::Twox256 => twox_256(value).to_vec(),
+		StorageHasher::Twox64Concat => [&twox_64(value)[..], value].concat(),
+		StorageHasher::Identity => value.to_vec(),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn plain_key_of_system_events() {
+		assert_eq!(
+			hex::encode(plain("System", "Events").0),
+			"26aa394eea5630e07c48ae0c9558cef780d41e5e16056765bc8461851072c9d7"
+		);
+	}
+
+	#[test]
+	fn map_key_of_system_account() {
+		let alice = hex::decode("d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d")
+			.unwrap();
+		let key = with_keys(
+			"System",
+			"Account",
+			&[StorageHasher::Blake2_128Concat],
+			&[alice],
+		);
+
+		assert_eq!(
+			hex::encode(key),
+			"26aa394eea5630e07c48ae0c9558cef7b99d880ec681799c0cf30e8886371da9\
+			de1e86a9a8c739864cf3cc5ec2bea59f\
+			d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d"
+		);
+	}
+}
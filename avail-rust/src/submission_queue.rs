@@ -0,0 +1,157 @@
+use crate::avail::runtime_types::bounded_collections::bounded_vec::BoundedVec;
+use crate::retry::is_transient;
+use crate::rpcs::Rpc;
+use crate::transactions::{DataAvailability, Nonce, Options, SubmitDataTxSuccess};
+use crate::{AvailConfig, WaitFor, SDK};
+use jsonrpsee::core::client::Error as JsonRpseeError;
+use std::{sync::Arc, time::Duration};
+use subxt::{error::RpcError, tx::Signer};
+use tokio::sync::Mutex;
+
+/// How many times a submission failing with a transport error is sent before giving up.
+pub const SUBMISSION_ATTEMPTS: u32 = 3;
+
+/// Delay between two submissions of the same transaction.
+pub const SUBMISSION_RETRY_DELAY: Duration = Duration::from_millis(500);
+
+/// Transaction pool error codes meaning the pool already holds a transaction with our nonce.
+const POOL_ALREADY_IMPORTED: i32 = 1013;
+const POOL_TOO_LOW_PRIORITY: i32 = 1014;
+
+/// Submits data from a single account, assigning sequential nonces locally.
+///
+/// Transactions are signed and handed to the node one at a time in the order they were
+/// enqueued, while waiting for inclusion happens concurrently. A submission failing with a
+/// transport error is resent with the same nonce, see [`SUBMISSION_ATTEMPTS`]. If the pool
+/// answers a resend with "already imported" or "priority too low", an earlier attempt did
+/// reach the node: the nonce is kept as used and an error naming the transaction hash is
+/// returned, since its progress can no longer be watched. Any other failure, including the
+/// transaction becoming invalid or being dropped while it is watched, makes the next
+/// submission fetch the nonce from the node again. Clones share the same nonce counter.
+#[derive(Clone)]
+pub struct SubmissionQueue<S> {
+	data_availability: DataAvailability,
+	rpc: Rpc,
+	signer: S,
+	options: Options,
+	wait_for: WaitFor,
+	next_nonce: Arc<Mutex<Option<u32>>>,
+}
+
+impl<S: Signer<AvailConfig>> SubmissionQueue<S> {
+	/// Submissions wait for block inclusion by default.
+	pub fn new(sdk: &SDK, signer: S) -> Self {
+		Self {
+			data_availability: sdk.tx.data_availability.clone(),
+			rpc: sdk.rpc.clone(),
+			signer,
+			options: Options::new(),
+			wait_for: WaitFor::BlockInclusion,
+			next_nonce: Arc::new(Mutex::new(None)),
+		}
+	}
+
+	/// Options used for every submission. The nonce option is ignored.
+	pub fn options(mut self, options: Options) -> Self {
+		self.options = options;
+		self
+	}
+
+	pub fn wait_for(mut self, wait_for: WaitFor) -> Self {
+		self.wait_for = wait_for;
+		self
+	}
+
+	pub async fn enqueue(&self, data: Vec<u8>) -> Result<SubmitDataTxSuccess, String> {
+		let app_id = self.options.app_id.unwrap_or_default();
+		let mut next_nonce = self.next_nonce.lock().await;
+
+		let nonce = match *next_nonce {
+			Some(nonce) => nonce,
+			None => {
+				let account_id = self.signer.account_id();
+				self.rpc
+					.system
+					.account_next_index(account_id.to_string())
+					.await
+					.map_err(|e| e.to_string())?
+			},
+		};
+
+		let options = self.options.nonce(Nonce::Custom(nonce));
+		let tx = self
+			.data_availability
+			.create_submit_data(BoundedVec(data), &self.signer, Some(options))
+			.await?;
+
+		let mut attempt = 0;
+		let tx_progress = loop {
+			attempt += 1;
+			match tx.submit_and_watch().await {
+				Ok(tx_progress) => break tx_progress,
+				Err(subxt::Error::Rpc(error))
+					if attempt < SUBMISSION_ATTEMPTS && is_transient(&error) =>
+				{
+					tokio::time::sleep(SUBMISSION_RETRY_DELAY).await;
+				},
+				Err(subxt::Error::Rpc(error)) if attempt > 1 && is_in_pool(&error) => {
+					*next_nonce = Some(nonce + 1);
+					return Err(std::format!(
+						"Transaction {:?} was already submitted by an earlier attempt. Error: {}",
+						tx.hash(),
+						error
+					));
+				},
+				Err(error) => {
+					*next_nonce = None;
+					return Err(error.to_string());
+				},
+			}
+		};
+
+		*next_nonce = Some(nonce + 1);
+		drop(next_nonce);
+
+		let result = self
+			.data_availability
+			.watch_submit_data(Ok(tx_progress), self.wait_for, app_id)
+			.await;
+		if result.is_err() {
+			*self.next_nonce.lock().await = None;
+		}
+		result
+	}
+}
+
+/// The pool already holds a transaction with the same nonce, e.g. the one sent by an earlier
+/// attempt whose reply was lost.
+fn is_in_pool(error: &RpcError) -> bool {
+	let RpcError::ClientError(error) = error else {
+		return false;
+	};
+
+	match error.downcast_ref::<JsonRpseeError>() {
+		Some(JsonRpseeError::Call(error)) => {
+			matches!(error.code(), POOL_ALREADY_IMPORTED | POOL_TOO_LOW_PRIORITY)
+		},
+		_ => false,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use jsonrpsee::types::ErrorObject;
+
+	fn call_error(code: i32) -> RpcError {
+		let error = ErrorObject::owned(code, "Transaction pool error", None::<()>);
+		RpcError::ClientError(Box::new(JsonRpseeError::Call(error)))
+	}
+
+	#[test]
+	fn pool_replies_for_taken_nonce_are_recognized() {
+		assert!(is_in_pool(&call_error(POOL_ALREADY_IMPORTED)));
+		assert!(is_in_pool(&call_error(POOL_TOO_LOW_PRIORITY)));
+		assert!(!is_in_pool(&call_error(1010)));
+	}
+}
@@ -0,0 +1,98 @@
+use jsonrpsee::core::client::Error as JsonRpseeError;
+use serde_json::value::RawValue;
+use std::sync::{
+	atomic::{AtomicU32, AtomicUsize, Ordering},
+	Arc,
+};
+use std::time::Duration;
+use subxt::{
+	backend::rpc::{RawRpcFuture, RawRpcSubscription, RpcClientT},
+	error::RpcError,
+};
+
+/// RPC client answering every request with `1`, used to test the client middlewares.
+/// Subscriptions are rejected.
+pub(crate) struct TestClient {
+	failures: u32,
+	closed: bool,
+	delay: Duration,
+	calls: AtomicU32,
+	in_flight: AtomicUsize,
+	max_in_flight: AtomicUsize,
+}
+
+impl TestClient {
+	pub fn new() -> Self {
+		Self {
+			failures: 0,
+			closed: false,
+			delay: Duration::ZERO,
+			calls: AtomicU32::new(0),
+			in_flight: AtomicUsize::new(0),
+			max_in_flight: AtomicUsize::new(0),
+		}
+	}
+
+	/// Fails the first `failures` requests with a request timeout.
+	pub fn failing(mut self, failures: u32) -> Self {
+		self.failures = failures;
+		self
+	}
+
+	/// Fails every request as if the connection was closed.
+	pub fn closed(mut self) -> Self {
+		self.closed = true;
+		self
+	}
+
+	/// Waits `delay` before answering a request.
+	pub fn delay(mut self, delay: Duration) -> Self {
+		self.delay = delay;
+		self
+	}
+
+	/// Number of requests received so far.
+	pub fn calls(&self) -> u32 {
+		self.calls.load(Ordering::SeqCst)
+	}
+
+	/// Highest number of requests that were processed at the same time.
+	pub fn max_in_flight(&self) -> usize {
+		self.max_in_flight.load(Ordering::SeqCst)
+	}
+}
+
+impl RpcClientT for TestClient {
+	fn request_raw<'a>(
+		&'a self,
+		_method: &'a str,
+		_params: Option<Box<RawValue>>,
+	) -> RawRpcFuture<'a, Box<RawValue>> {
+		Box::pin(async move {
+			let call = self.calls.fetch_add(1, Ordering::SeqCst);
+			let in_flight = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+			self.max_in_flight.fetch_max(in_flight, Ordering::SeqCst);
+			tokio::time::sleep(self.delay).await;
+			self.in_flight.fetch_sub(1, Ordering::SeqCst);
+
+			if self.closed {
+				let error = JsonRpseeError::RestartNeeded(Arc::new(JsonRpseeError::RequestTimeout));
+				return Err(RpcError::ClientError(Box::new(error)));
+			}
+			if call < self.failures {
+				let error = JsonRpseeError::RequestTimeout;
+				return Err(RpcError::ClientError(Box::new(error)));
+			}
+			Ok(RawValue::from_string(String::from("1")).unwrap())
+		})
+	}
+
+	fn subscribe_raw<'a>(
+		&'a self,
+		_sub: &'a str,
+		_params: Option<Box<RawValue>>,
+		_unsub: &'a str,
+	) -> RawRpcFuture<'a, RawRpcSubscription> {
+		Box::pin(async move { Err(RpcError::RequestRejected(String::from("unsupported"))) })
+	}
+}
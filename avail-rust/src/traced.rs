@@ -0,0 +1,74 @@
+use serde_json::value::RawValue;
+use std::time::Instant;
+use subxt::backend::rpc::{RawRpcFuture, RawRpcSubscription, RpcClientT};
+use tracing::Instrument;
+
+/// RPC client recording a `tracing` span for every request, carrying the method name and the
+/// size of the params. Completion is logged with the latency and the size of the response.
+/// With the `tracing` feature enabled, SDKs created by [`crate::SDK::with_options`],
+/// [`crate::SDK::with_endpoints`] and [`crate::SDK::with_transport`] use it automatically.
+pub struct TracingClient<C> {
+	inner: C,
+}
+
+impl<C: RpcClientT> TracingClient<C> {
+	pub fn new(inner: C) -> Self {
+		Self { inner }
+	}
+}
+
+impl<C: RpcClientT> RpcClientT for TracingClient<C> {
+	fn request_raw<'a>(
+		&'a self,
+		method: &'a str,
+		params: Option<Box<RawValue>>,
+	) -> RawRpcFuture<'a, Box<RawValue>> {
+		let params_size = params_size(&params);
+		let span = tracing::debug_span!("rpc_request", method, params_size);
+
+		Box::pin(
+			async move {
+				let start = Instant::now();
+				let result = self.inner.request_raw(method, params).await;
+				let latency_ms = start.elapsed().as_millis() as u64;
+				match &result {
+					Ok(response) => {
+						let response_size = response.get().len();
+						tracing::debug!(latency_ms, response_size, "RPC request completed");
+					},
+					Err(error) => tracing::warn!(latency_ms, %error, "RPC request failed"),
+				}
+				result
+			}
+			.instrument(span),
+		)
+	}
+
+	fn subscribe_raw<'a>(
+		&'a self,
+		sub: &'a str,
+		params: Option<Box<RawValue>>,
+		unsub: &'a str,
+	) -> RawRpcFuture<'a, RawRpcSubscription> {
+		let params_size = params_size(&params);
+		let span = tracing::debug_span!("rpc_subscribe", method = sub, params_size);
+
+		Box::pin(
+			async move {
+				let start = Instant::now();
+				let result = self.inner.subscribe_raw(sub, params, unsub).await;
+				let latency_ms = start.elapsed().as_millis() as u64;
+				match &result {
+					Ok(_) => tracing::debug!(latency_ms, "RPC subscription established"),
+					Err(error) => tracing::warn!(latency_ms, %error, "RPC subscription failed"),
+				}
+				result
+			}
+			.instrument(span),
+		)
+	}
+}
+
+fn params_size(params: &Option<Box<RawValue>>) -> usize {
+	params.as_ref().map_or(0, |p| p.get().len())
+}
@@ -1,10 +1,10 @@
 use crate::rpcs::Rpc;
 use crate::sdk::WaitFor;
-use crate::{avail, AccountId, AvailBlocksClient, AvailConfig, BlockHash, TxApi};
+use crate::{avail, AccountId, AvailBlocksClient, AvailConfig, BlockHash, TxApi, TxHash};
 
 use std::str::FromStr;
 use subxt::blocks::ExtrinsicEvents;
-use subxt_signer::sr25519::Keypair;
+use subxt::tx::Signer;
 
 use avail::balances::events as BalancesEvents;
 use avail::system::events as SystemEvents;
@@ -17,7 +17,7 @@ pub struct TransferAllTxSuccess {
 	pub event: BalancesEvents::Transfer,
 	pub event2: Option<SystemEvents::KilledAccount>,
 	pub events: ExtrinsicEvents<AvailConfig>,
-	pub tx_hash: BlockHash,
+	pub tx_hash: TxHash,
 	pub tx_index: u32,
 	pub block_hash: BlockHash,
 	pub block_number: u32,
@@ -28,7 +28,7 @@ pub struct TransferAllowDeathTxSuccess {
 	pub event: BalancesEvents::Transfer,
 	pub event2: Option<SystemEvents::KilledAccount>,
 	pub events: ExtrinsicEvents<AvailConfig>,
-	pub tx_hash: BlockHash,
+	pub tx_hash: TxHash,
 	pub tx_index: u32,
 	pub block_hash: BlockHash,
 	pub block_number: u32,
@@ -38,7 +38,7 @@ pub struct TransferAllowDeathTxSuccess {
 pub struct TransferKeepAliveTxSuccess {
 	pub event: BalancesEvents::Transfer,
 	pub events: ExtrinsicEvents<AvailConfig>,
-	pub tx_hash: BlockHash,
+	pub tx_hash: TxHash,
 	pub tx_index: u32,
 	pub block_hash: BlockHash,
 	pub block_number: u32,
@@ -65,7 +65,7 @@ impl Balances {
 		dest: &str,
 		keep_alive: bool,
 		wait_for: WaitFor,
-		account: &Keypair,
+		account: &impl Signer<AvailConfig>,
 		options: Option<Options>,
 	) -> Result<TransferAllTxSuccess, String> {
 		let dest = match AccountId::from_str(dest) {
@@ -73,7 +73,7 @@ impl Balances {
 			Err(error) => return Err(std::format!("{:?}", error)),
 		};
 
-		let account_id = account.public_key().to_account_id();
+		let account_id = account.account_id();
 		let params =
 			from_options_to_params(options, &self.rpc_client, account_id, &self.blocks).await?;
 		let call = avail::tx().balances().transfer_all(dest.into(), keep_alive);
@@ -111,7 +111,7 @@ impl Balances {
 		dest: &str,
 		amount: u128,
 		wait_for: WaitFor,
-		account: &Keypair,
+		account: &impl Signer<AvailConfig>,
 		options: Option<Options>,
 	) -> Result<TransferAllowDeathTxSuccess, String> {
 		let dest = match AccountId::from_str(dest) {
@@ -119,7 +119,7 @@ impl Balances {
 			Err(error) => return Err(std::format!("{:?}", error)),
 		};
 
-		let account_id = account.public_key().to_account_id();
+		let account_id = account.account_id();
 		let params =
 			from_options_to_params(options, &self.rpc_client, account_id, &self.blocks).await?;
 		let call = avail::tx()
@@ -159,7 +159,7 @@ impl Balances {
 		dest: &str,
 		value: u128,
 		wait_for: WaitFor,
-		account: &Keypair,
+		account: &impl Signer<AvailConfig>,
 		options: Option<Options>,
 	) -> Result<TransferKeepAliveTxSuccess, String> {
 		let dest = match AccountId::from_str(dest) {
@@ -167,7 +167,7 @@ impl Balances {
 			Err(error) => return Err(std::format!("{:?}", error)),
 		};
 
-		let account_id = account.public_key().to_account_id();
+		let account_id = account.account_id();
 		let params =
 			from_options_to_params(options, &self.rpc_client, account_id, &self.blocks).await?;
 		let call = avail::tx()
@@ -5,24 +5,29 @@ use crate::api_dev::api::Call;
 use crate::rpcs::Rpc;
 use crate::sdk::WaitFor;
 use crate::utils_raw::{fetch_transaction, progress_transaction};
-use crate::{avail, AvailBlocksClient, AvailConfig, BlockHash, TxApi};
+use crate::{
+	avail, AccountId, Api, AvailBlocksClient, AvailConfig, AvailConstantsClient, BlockHash, TxApi,
+	TxHash,
+};
 
 use subxt::blocks::ExtrinsicEvents;
-use subxt_signer::sr25519::Keypair;
+use subxt::tx::{Signer, SubmittableExtrinsic, TxProgress};
 
 use avail::data_availability::calls::types as DataAvailabilityCalls;
 use avail::data_availability::events as DataAvailabilityEvents;
 use avail::sudo::events as SudoEvents;
 
-use super::options::{from_options_to_params, Options};
-use super::{block_and_tx_hash, progress_transaction_ex};
+use super::options::{from_options_to_params, Options, SignatureScheme};
+use super::{block_and_tx_hash, estimate_signed_size, progress_transaction_ex};
 
 #[derive(Debug)]
 pub struct SubmitDataTxSuccess {
 	pub event: DataAvailabilityEvents::DataSubmitted,
 	pub events: ExtrinsicEvents<AvailConfig>,
 	pub tx_data: DataAvailabilityCalls::SubmitData,
-	pub tx_hash: BlockHash,
+	/// Application id the data was submitted with.
+	pub app_id: u32,
+	pub tx_hash: TxHash,
 	pub tx_index: u32,
 	pub block_hash: BlockHash,
 	pub block_number: u32,
@@ -32,7 +37,7 @@ pub struct SubmitDataTxSuccess {
 pub struct CreateApplicationKeyTxSuccess {
 	pub event: DataAvailabilityEvents::ApplicationKeyCreated,
 	pub events: ExtrinsicEvents<AvailConfig>,
-	pub tx_hash: BlockHash,
+	pub tx_hash: TxHash,
 	pub tx_index: u32,
 	pub block_hash: BlockHash,
 	pub block_number: u32,
@@ -42,7 +47,7 @@ pub struct CreateApplicationKeyTxSuccess {
 pub struct SetApplicationKeyTxSuccess {
 	pub event: DataAvailabilityEvents::ApplicationKeySet,
 	pub events: ExtrinsicEvents<AvailConfig>,
-	pub tx_hash: BlockHash,
+	pub tx_hash: TxHash,
 	pub tx_index: u32,
 	pub block_hash: BlockHash,
 	pub block_number: u32,
@@ -52,7 +57,7 @@ pub struct SetApplicationKeyTxSuccess {
 pub struct SubmitBlockLengthProposalTxSuccess {
 	pub event: DataAvailabilityEvents::BlockLengthProposalSubmitted,
 	pub events: ExtrinsicEvents<AvailConfig>,
-	pub tx_hash: BlockHash,
+	pub tx_hash: TxHash,
 	pub tx_index: u32,
 	pub block_hash: BlockHash,
 	pub block_number: u32,
@@ -62,7 +67,7 @@ pub struct SubmitBlockLengthProposalTxSuccess {
 pub struct SetSubmitDataFeeModifierTxSuccess {
 	pub event: DataAvailabilityEvents::SubmitDataFeeModifierSet,
 	pub events: ExtrinsicEvents<AvailConfig>,
-	pub tx_hash: BlockHash,
+	pub tx_hash: TxHash,
 	pub tx_index: u32,
 	pub block_hash: BlockHash,
 	pub block_number: u32,
@@ -73,34 +78,83 @@ pub struct DataAvailability {
 	api: TxApi,
 	rpc_client: Rpc,
 	blocks: AvailBlocksClient,
+	constants: AvailConstantsClient,
 }
 
 impl DataAvailability {
-	pub fn new(api: TxApi, rpc_client: Rpc, blocks: AvailBlocksClient) -> Self {
+	pub fn new(
+		api: TxApi,
+		rpc_client: Rpc,
+		blocks: AvailBlocksClient,
+		constants: AvailConstantsClient,
+	) -> Self {
 		Self {
 			api,
 			rpc_client,
 			blocks,
+			constants,
 		}
 	}
 
+	/// `data` is submitted as it is; SCALE encoding, including the length prefix, is done
+	/// when the call is encoded. Empty data and data longer than the runtime's
+	/// `MaxAppDataLength` are rejected before anything is signed or submitted.
 	pub async fn submit_data(
 		&self,
 		data: Data,
 		wait_for: WaitFor,
-		account: &Keypair,
+		account: &impl Signer<AvailConfig>,
 		options: Option<Options>,
 	) -> Result<SubmitDataTxSuccess, String> {
-		let account_id = account.public_key().to_account_id();
+		let app_id = options.and_then(|o| o.app_id).unwrap_or_default();
+		let tx = self.create_submit_data(data, account, options).await?;
+		let maybe_tx_progress = tx.submit_and_watch().await;
+
+		self.watch_submit_data(maybe_tx_progress, wait_for, app_id)
+			.await
+	}
+
+	/// Validates `data` and signs the `submit_data` call without submitting it.
+	pub(crate) async fn create_submit_data(
+		&self,
+		data: Data,
+		account: &impl Signer<AvailConfig>,
+		options: Option<Options>,
+	) -> Result<SubmittableExtrinsic<AvailConfig, Api>, String> {
+		if data.0.is_empty() {
+			return Err(String::from("Data cannot be empty"));
+		}
+		let max_length_address = avail::constants().data_availability().max_app_data_length();
+		let max_length = self
+			.constants
+			.at(&max_length_address)
+			.map_err(|e| e.to_string())?;
+		if data.0.len() > max_length as usize {
+			return Err(std::format!(
+				"Data is too long. Length: {}, Max length: {}",
+				data.0.len(),
+				max_length
+			));
+		}
+
+		let account_id = account.account_id();
 		let params =
 			from_options_to_params(options, &self.rpc_client, account_id, &self.blocks).await?;
 		let call = avail::tx().data_availability().submit_data(data);
 
-		let maybe_tx_progress = self
-			.api
-			.sign_and_submit_then_watch(&call, account, params)
-			.await;
+		self.api
+			.create_signed(&call, account, params)
+			.await
+			.map_err(|e| e.to_string())
+	}
 
+	/// Waits for a submitted `submit_data` extrinsic and collects its result.
+	pub(crate) async fn watch_submit_data(
+		&self,
+		maybe_tx_progress: Result<TxProgress<AvailConfig, Api>, subxt::Error>,
+		wait_for: WaitFor,
+		app_id: u32,
+	) -> Result<SubmitDataTxSuccess, String> {
 		let (events, data) =
 			progress_transaction_ex(maybe_tx_progress, wait_for, &self.blocks).await?;
 		let (block_hash, block_number, tx_hash, tx_index) = data;
@@ -116,6 +170,7 @@ impl DataAvailability {
 			event,
 			events,
 			tx_data,
+			app_id,
 			tx_hash,
 			tx_index,
 			block_hash,
@@ -123,14 +178,32 @@ impl DataAvailability {
 		})
 	}
 
+	/// Length in bytes of the `submit_data` extrinsic `account` would submit, including the
+	/// signature of `scheme` and the length prefix, so oversized data can be rejected before
+	/// signing.
+	pub async fn estimate_submit_data_size(
+		&self,
+		data: &Data,
+		account: &AccountId,
+		scheme: SignatureScheme,
+		options: Option<Options>,
+	) -> Result<usize, String> {
+		let params =
+			from_options_to_params(options, &self.rpc_client, account.clone(), &self.blocks)
+				.await?;
+		let call = avail::tx().data_availability().submit_data(data.clone());
+
+		estimate_signed_size(&self.api, &call, account, scheme, params).await
+	}
+
 	pub async fn create_application_key(
 		&self,
 		key: Key,
 		wait_for: WaitFor,
-		account: &Keypair,
+		account: &impl Signer<AvailConfig>,
 		options: Option<Options>,
 	) -> Result<CreateApplicationKeyTxSuccess, String> {
-		let account_id = account.public_key().to_account_id();
+		let account_id = account.account_id();
 		let params =
 			from_options_to_params(options, &self.rpc_client, account_id, &self.blocks).await?;
 		let call = avail::tx().data_availability().create_application_key(key);
@@ -164,10 +237,10 @@ impl DataAvailability {
 		old_key: Key,
 		new_key: Key,
 		wait_for: WaitFor,
-		account: &Keypair,
+		account: &impl Signer<AvailConfig>,
 		options: Option<Options>,
 	) -> Result<SetApplicationKeyTxSuccess, String> {
-		let account_id = account.public_key().to_account_id();
+		let account_id = account.account_id();
 		let params =
 			from_options_to_params(options, &self.rpc_client, account_id, &self.blocks).await?;
 		let call = Call::DataAvailability(
@@ -226,10 +299,10 @@ impl DataAvailability {
 		rows: u32,
 		cols: u32,
 		wait_for: WaitFor,
-		account: &Keypair,
+		account: &impl Signer<AvailConfig>,
 		options: Option<Options>,
 	) -> Result<SubmitBlockLengthProposalTxSuccess, String> {
-		let account_id = account.public_key().to_account_id();
+		let account_id = account.account_id();
 		let params =
 			from_options_to_params(options, &self.rpc_client, account_id, &self.blocks).await?;
 		let call = Call::DataAvailability(
@@ -289,10 +362,10 @@ impl DataAvailability {
 		&self,
 		modifier: DispatchFeeModifier,
 		wait_for: WaitFor,
-		account: &Keypair,
+		account: &impl Signer<AvailConfig>,
 		options: Option<Options>,
 	) -> Result<SetSubmitDataFeeModifierTxSuccess, String> {
-		let account_id = account.public_key().to_account_id();
+		let account_id = account.account_id();
 		let params =
 			from_options_to_params(options, &self.rpc_client, account_id, &self.blocks).await?;
 		let call = Call::DataAvailability(
@@ -350,7 +423,7 @@ impl DataAvailability {
 
 pub async fn tx_data_da_submit_data(
 	block_hash: BlockHash,
-	tx_hash: BlockHash,
+	tx_hash: TxHash,
 	blocks: &AvailBlocksClient,
 ) -> Result<DataAvailabilityCalls::SubmitData, String> {
 	let transaction =
@@ -4,20 +4,26 @@ mod nom_pools;
 mod options;
 mod session;
 mod staking;
+mod utility;
 
 pub use balances::*;
 pub use da::*;
 pub use nom_pools::*;
-pub use options::{Mortality, Nonce, Options};
+pub use options::{Mortality, Nonce, Options, SignatureScheme, Tip};
 pub use session::*;
 pub use staking::*;
+pub use utility::*;
 
 use crate::{
-	rpcs::Rpc, utils_raw::progress_transaction, Api, AvailBlocksClient, AvailConfig, BlockHash,
-	TransactionInBlock, WaitFor,
+	rpcs::Rpc, utils_raw::progress_transaction, AccountId, Address, Api, AvailBlocksClient,
+	AvailConfig, BlockHash, TransactionInBlock, TxApi, WaitFor,
 };
 use sp_core::H256;
-use subxt::{blocks::ExtrinsicEvents, tx::TxProgress};
+use subxt::{
+	blocks::ExtrinsicEvents,
+	client::OfflineClientT,
+	tx::{PartialExtrinsic, Payload, TxProgress},
+};
 
 pub type Params =
 	<<AvailConfig as subxt::Config>::ExtrinsicParams as subxt::config::ExtrinsicParams<
@@ -31,6 +37,7 @@ pub struct Transactions {
 	pub data_availability: DataAvailability,
 	pub session: Session,
 	pub nomination_pools: NominationPools,
+	pub utility: Utility,
 }
 
 impl Transactions {
@@ -45,9 +52,11 @@ impl Transactions {
 				tx.clone(),
 				rpc_client.clone(),
 				blocks.clone(),
+				api.constants(),
 			),
 			session: Session::new(tx.clone(), rpc_client.clone(), blocks.clone()),
 			nomination_pools: NominationPools::new(tx.clone(), rpc_client.clone(), blocks.clone()),
+			utility: Utility::new(tx.clone(), rpc_client.clone(), blocks.clone()),
 		}
 	}
 }
@@ -78,6 +87,33 @@ async fn get_block_number(
 	Ok(block_number)
 }
 
+/// Length of the extrinsic `account` would submit for `call`, including the signature and the
+/// length prefix. Nothing is signed; a placeholder signature of `scheme` is used instead.
+async fn estimate_signed_size<Call: Payload>(
+	api: &TxApi,
+	call: &Call,
+	account: &AccountId,
+	scheme: SignatureScheme,
+	params: Params,
+) -> Result<usize, String> {
+	let partial = api
+		.create_partial_signed(call, account, params)
+		.await
+		.map_err(|e| e.to_string())?;
+
+	Ok(signed_size(&partial, account, scheme))
+}
+
+fn signed_size<C: OfflineClientT<AvailConfig>>(
+	partial: &PartialExtrinsic<AvailConfig, C>,
+	account: &AccountId,
+	scheme: SignatureScheme,
+) -> usize {
+	let address = Address::Id(account.clone());
+	let tx = partial.sign_with_address_and_signature(&address, &scheme.placeholder());
+	tx.encoded().len()
+}
+
 async fn progress_transaction_ex(
 	maybe_tx_progress: Result<TxProgress<AvailConfig, Api>, subxt::Error>,
 	wait_for: WaitFor,
@@ -102,6 +138,125 @@ async fn progress_transaction_ex(
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use crate::avail::runtime_types::bounded_collections::bounded_vec::BoundedVec;
+	use crate::avail::runtime_types::da_control::pallet::Call as DataAvailabilityCall;
+	use crate::avail::runtime_types::da_runtime::RuntimeCall;
+	use crate::primitives::block::extrinsics_params::OnlyCodecExtra;
+	use crate::{AppUncheckedExtrinsic, SecretUri};
+	use codec::{Compact, Decode, Encode};
+	use sp_core::Pair;
+	use std::str::FromStr;
+	use subxt::tx::Signer;
+	use subxt_core::utils::Era;
+
+	/// Signs with an ed25519 key, which subxt-signer does not provide.
+	struct Ed25519Signer(sp_core::ed25519::Pair);
+
+	impl Signer<AvailConfig> for Ed25519Signer {
+		fn account_id(&self) -> AccountId {
+			AccountId(self.0.public().0)
+		}
+
+		fn address(&self) -> Address {
+			Address::Id(self.account_id())
+		}
+
+		fn sign(&self, signer_payload: &[u8]) -> Signature {
+			Signature::Ed25519(self.0.sign(signer_payload).0)
+		}
+	}
+
+	/// Immortal `submit_data(b"data")` with nonce 5 and app id 1, signed for spec version 24,
+	/// transaction version 1 and a genesis hash of `0xab` bytes, without a metadata hash.
+	fn signed_submit_data(signer: &impl Signer<AvailConfig>) -> String {
+		let function = RuntimeCall::DataAvailability(DataAvailabilityCall::submit_data {
+			data: BoundedVec(b"data".to_vec()),
+		});
+		let extra: OnlyCodecExtra = (
+			(),
+			(),
+			(),
+			(),
+			Era::Immortal,
+			Compact(5),
+			(),
+			Compact(0),
+			avail_core::AppId(1),
+			0,
+		);
+		let genesis_hash = BlockHash::repeat_byte(0xab);
+		let additional = (24u32, 1u32, genesis_hash, genesis_hash, None::<[u8; 32]>);
+		let payload = (&function, &extra, additional).encode();
+
+		let signature = (signer.address(), signer.sign(&payload), extra);
+		let extrinsic = AppUncheckedExtrinsic {
+			signature: Some(signature),
+			function,
+		};
+		hex::encode(extrinsic.encode())
+	}
+
+	#[test]
+	fn ed25519_signed_extrinsic_matches_reference() {
+		let signer = Ed25519Signer(sp_core::ed25519::Pair::from_seed(&[0x22; 32]));
+
+		assert_eq!(
+			signed_submit_data(&signer),
+			"bd018400a09aa5f47a6759802ff955f8dc2d2a14a5c99d23be97f864127ff9383455a4f0\
+			000e6cbf0bc62139977f441d8d5bda1ff7d62daca797dfd6c90f8bf939922c4ff298f79f518eaef3\
+			0b21747caa3ac20cdbcee63fabdada37d16b97e26fe941870000140004001d011064617461"
+		);
+	}
+
+	#[test]
+	fn ecdsa_signed_extrinsic_matches_reference() {
+		let uri = SecretUri::from_str(&std::format!("0x{}", "11".repeat(32))).unwrap();
+		let signer = subxt_signer::ecdsa::Keypair::from_uri(&uri).unwrap();
+
+		assert_eq!(
+			signed_submit_data(&signer),
+			"c10184002d95ebcdc7d1e528eb8dc339a5c44721d39743932b82a3303f5a22ac1e470d360206d28b\
+			d5ba212395e3587102be5f2e9645c0ed75fc3506962b868b0ac2461e8153624e4044d4b566241d5d\
+			b485076ecd7f30246618f22f89c08e03843ad71aa10000140004001d011064617461"
+		);
+	}
+
+	#[test]
+	fn signed_size_matches_signed_extrinsic() {
+		use crate::utils_raw::create_partial_signed_offline;
+		use crate::OfflineParams;
+		use subxt::Metadata;
+
+		let metadata = include_bytes!("../../../avail-subxt/avail.metadata.scale");
+		let metadata = Metadata::decode(&mut metadata.as_slice()).unwrap();
+		let params = OfflineParams {
+			genesis_hash: BlockHash::repeat_byte(0xab),
+			spec_version: 24,
+			transaction_version: 1,
+			nonce: 5,
+			mortality: None,
+			tip: 0,
+			app_id: 1,
+		};
+		let uri = SecretUri::from_str(&std::format!("0x{}", "11".repeat(32))).unwrap();
+		let ecdsa = subxt_signer::ecdsa::Keypair::from_uri(&uri).unwrap();
+		let sr25519 = subxt_signer::sr25519::dev::alice();
+
+		for len in [4, 300] {
+			let data = BoundedVec(vec![0u8; len]);
+			let call = crate::avail::tx().data_availability().submit_data(data);
+			let call = call.unvalidated();
+			let partial = create_partial_signed_offline(&call, metadata.clone(), params).unwrap();
+
+			let account = Signer::<AvailConfig>::account_id(&sr25519);
+			let size = signed_size(&partial, &account, SignatureScheme::Sr25519);
+			assert_eq!(size, partial.sign(&sr25519).encoded().len());
+
+			let account = Signer::<AvailConfig>::account_id(&ecdsa);
+			let size = signed_size(&partial, &account, SignatureScheme::Ecdsa);
+			assert_eq!(size, partial.sign(&ecdsa).encoded().len());
+		}
+	}
 
 	#[tokio::test]
 	async fn testing_function() {
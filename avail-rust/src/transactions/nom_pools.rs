@@ -6,11 +6,11 @@ use crate::avail::runtime_types::sp_arithmetic::per_things::Perbill;
 use crate::rpcs::Rpc;
 use crate::sdk::WaitFor;
 use crate::utils_raw::fetch_transaction;
-use crate::{avail, AccountId, AvailBlocksClient, AvailConfig, BlockHash, TxApi};
+use crate::{avail, AccountId, AvailBlocksClient, AvailConfig, BlockHash, TxApi, TxHash};
 
 use std::str::FromStr;
 use subxt::blocks::ExtrinsicEvents;
-use subxt_signer::sr25519::Keypair;
+use subxt::tx::Signer;
 
 use avail::nomination_pools::calls::types as NominationPoolsCalls;
 use avail::nomination_pools::events as NominationPoolsEvents;
@@ -23,7 +23,7 @@ pub struct PoolCreateTxSuccess {
 	pub event: NominationPoolsEvents::Created,
 	pub event2: NominationPoolsEvents::Bonded,
 	pub events: ExtrinsicEvents<AvailConfig>,
-	pub tx_hash: BlockHash,
+	pub tx_hash: TxHash,
 	pub tx_index: u32,
 	pub block_hash: BlockHash,
 	pub block_number: u32,
@@ -34,7 +34,7 @@ pub struct PoolCreateWithPoolIdTxSuccess {
 	pub event: NominationPoolsEvents::Created,
 	pub event2: NominationPoolsEvents::Bonded,
 	pub events: ExtrinsicEvents<AvailConfig>,
-	pub tx_hash: BlockHash,
+	pub tx_hash: TxHash,
 	pub tx_index: u32,
 	pub block_hash: BlockHash,
 	pub block_number: u32,
@@ -44,7 +44,7 @@ pub struct PoolCreateWithPoolIdTxSuccess {
 pub struct PoolJoinTxSuccess {
 	pub event: NominationPoolsEvents::Bonded,
 	pub events: ExtrinsicEvents<AvailConfig>,
-	pub tx_hash: BlockHash,
+	pub tx_hash: TxHash,
 	pub tx_index: u32,
 	pub block_hash: BlockHash,
 	pub block_number: u32,
@@ -54,7 +54,7 @@ pub struct PoolJoinTxSuccess {
 pub struct PoolNominateTxSuccess {
 	pub events: ExtrinsicEvents<AvailConfig>,
 	pub tx_data: NominationPoolsCalls::Nominate,
-	pub tx_hash: BlockHash,
+	pub tx_hash: TxHash,
 	pub tx_index: u32,
 	pub block_hash: BlockHash,
 	pub block_number: u32,
@@ -64,7 +64,7 @@ pub struct PoolNominateTxSuccess {
 pub struct PoolBondExtraTxSuccess {
 	pub event: NominationPoolsEvents::Bonded,
 	pub events: ExtrinsicEvents<AvailConfig>,
-	pub tx_hash: BlockHash,
+	pub tx_hash: TxHash,
 	pub tx_index: u32,
 	pub block_hash: BlockHash,
 	pub block_number: u32,
@@ -74,7 +74,7 @@ pub struct PoolBondExtraTxSuccess {
 pub struct PoolSetCommissionTxSuccess {
 	pub event: NominationPoolsEvents::PoolCommissionUpdated,
 	pub events: ExtrinsicEvents<AvailConfig>,
-	pub tx_hash: BlockHash,
+	pub tx_hash: TxHash,
 	pub tx_index: u32,
 	pub block_hash: BlockHash,
 	pub block_number: u32,
@@ -84,7 +84,7 @@ pub struct PoolSetCommissionTxSuccess {
 pub struct PoolSetStateTxSuccess {
 	pub event: Option<NominationPoolsEvents::StateChanged>,
 	pub events: ExtrinsicEvents<AvailConfig>,
-	pub tx_hash: BlockHash,
+	pub tx_hash: TxHash,
 	pub tx_index: u32,
 	pub block_hash: BlockHash,
 	pub block_number: u32,
@@ -94,7 +94,7 @@ pub struct PoolSetStateTxSuccess {
 pub struct PoolClaimPayoutTxSuccess {
 	pub event: Option<NominationPoolsEvents::PaidOut>,
 	pub events: ExtrinsicEvents<AvailConfig>,
-	pub tx_hash: BlockHash,
+	pub tx_hash: TxHash,
 	pub tx_index: u32,
 	pub block_hash: BlockHash,
 	pub block_number: u32,
@@ -103,7 +103,7 @@ pub struct PoolClaimPayoutTxSuccess {
 #[derive(Debug)]
 pub struct PoolChillTxSuccess {
 	pub events: ExtrinsicEvents<AvailConfig>,
-	pub tx_hash: BlockHash,
+	pub tx_hash: TxHash,
 	pub tx_index: u32,
 	pub block_hash: BlockHash,
 	pub block_number: u32,
@@ -112,7 +112,7 @@ pub struct PoolChillTxSuccess {
 #[derive(Debug)]
 pub struct PoolSetClaimPermissionTxSuccess {
 	pub events: ExtrinsicEvents<AvailConfig>,
-	pub tx_hash: BlockHash,
+	pub tx_hash: TxHash,
 	pub tx_index: u32,
 	pub block_hash: BlockHash,
 	pub block_number: u32,
@@ -122,7 +122,7 @@ pub struct PoolSetClaimPermissionTxSuccess {
 pub struct PoolClaimCommissionTxSuccess {
 	pub event: NominationPoolsEvents::PoolCommissionClaimed,
 	pub events: ExtrinsicEvents<AvailConfig>,
-	pub tx_hash: BlockHash,
+	pub tx_hash: TxHash,
 	pub tx_index: u32,
 	pub block_hash: BlockHash,
 	pub block_number: u32,
@@ -132,7 +132,7 @@ pub struct PoolClaimCommissionTxSuccess {
 pub struct PoolClaimPayoutOtherTxSuccess {
 	pub event: Option<NominationPoolsEvents::PaidOut>,
 	pub events: ExtrinsicEvents<AvailConfig>,
-	pub tx_hash: BlockHash,
+	pub tx_hash: TxHash,
 	pub tx_index: u32,
 	pub block_hash: BlockHash,
 	pub block_number: u32,
@@ -142,7 +142,7 @@ pub struct PoolClaimPayoutOtherTxSuccess {
 pub struct PoolUnbondTxSuccess {
 	pub event: Option<NominationPoolsEvents::Unbonded>,
 	pub events: ExtrinsicEvents<AvailConfig>,
-	pub tx_hash: BlockHash,
+	pub tx_hash: TxHash,
 	pub tx_index: u32,
 	pub block_hash: BlockHash,
 	pub block_number: u32,
@@ -151,7 +151,7 @@ pub struct PoolUnbondTxSuccess {
 #[derive(Debug)]
 pub struct PoolSetMetadataTxSuccess {
 	pub events: ExtrinsicEvents<AvailConfig>,
-	pub tx_hash: BlockHash,
+	pub tx_hash: TxHash,
 	pub tx_index: u32,
 	pub block_hash: BlockHash,
 	pub block_number: u32,
@@ -161,7 +161,7 @@ pub struct PoolSetMetadataTxSuccess {
 pub struct PoolWithdrawUnbondedTxSuccess {
 	pub event: Option<NominationPoolsEvents::Withdrawn>,
 	pub events: ExtrinsicEvents<AvailConfig>,
-	pub tx_hash: BlockHash,
+	pub tx_hash: TxHash,
 	pub tx_index: u32,
 	pub block_hash: BlockHash,
 	pub block_number: u32,
@@ -194,7 +194,7 @@ impl NominationPools {
 		pool_id: u32,
 		validators: Vec<String>,
 		wait_for: WaitFor,
-		account: &Keypair,
+		account: &impl Signer<AvailConfig>,
 		options: Option<Options>,
 	) -> Result<PoolNominateTxSuccess, String> {
 		let validators: Result<Vec<AccountId>, _> = validators
@@ -203,7 +203,7 @@ impl NominationPools {
 			.collect();
 		let validators = validators.map_err(|e| e.to_string())?;
 
-		let account_id = account.public_key().to_account_id();
+		let account_id = account.account_id();
 		let params =
 			from_options_to_params(options, &self.rpc_client, account_id, &self.blocks).await?;
 		let call = avail::tx().nomination_pools().nominate(pool_id, validators);
@@ -234,10 +234,10 @@ impl NominationPools {
 		amount: u128,
 		pool_id: u32,
 		wait_for: WaitFor,
-		account: &Keypair,
+		account: &impl Signer<AvailConfig>,
 		options: Option<Options>,
 	) -> Result<PoolJoinTxSuccess, String> {
-		let account_id = account.public_key().to_account_id();
+		let account_id = account.account_id();
 		let params =
 			from_options_to_params(options, &self.rpc_client, account_id, &self.blocks).await?;
 		let call = avail::tx().nomination_pools().join(amount, pool_id);
@@ -274,14 +274,14 @@ impl NominationPools {
 		bouncer: &str,
 		pool_id: u32,
 		wait_for: WaitFor,
-		account: &Keypair,
+		account: &impl Signer<AvailConfig>,
 		options: Option<Options>,
 	) -> Result<PoolCreateWithPoolIdTxSuccess, String> {
 		let root = AccountId::from_str(root).map_err(|e| e.to_string())?;
 		let nominator = AccountId::from_str(nominator).map_err(|e| e.to_string())?;
 		let bouncer = AccountId::from_str(bouncer).map_err(|e| e.to_string())?;
 
-		let account_id = account.public_key().to_account_id();
+		let account_id = account.account_id();
 		let params =
 			from_options_to_params(options, &self.rpc_client, account_id, &self.blocks).await?;
 		let call = avail::tx().nomination_pools().create_with_pool_id(
@@ -329,14 +329,14 @@ impl NominationPools {
 		nominator: &str,
 		bouncer: &str,
 		wait_for: WaitFor,
-		account: &Keypair,
+		account: &impl Signer<AvailConfig>,
 		options: Option<Options>,
 	) -> Result<PoolCreateTxSuccess, String> {
 		let root = AccountId::from_str(root).map_err(|e| e.to_string())?;
 		let nominator = AccountId::from_str(nominator).map_err(|e| e.to_string())?;
 		let bouncer = AccountId::from_str(bouncer).map_err(|e| e.to_string())?;
 
-		let account_id = account.public_key().to_account_id();
+		let account_id = account.account_id();
 		let params =
 			from_options_to_params(options, &self.rpc_client, account_id, &self.blocks).await?;
 		let call = avail::tx().nomination_pools().create(
@@ -380,10 +380,10 @@ impl NominationPools {
 		&self,
 		extra: BondExtra<u128>,
 		wait_for: WaitFor,
-		account: &Keypair,
+		account: &impl Signer<AvailConfig>,
 		options: Option<Options>,
 	) -> Result<PoolBondExtraTxSuccess, String> {
-		let account_id = account.public_key().to_account_id();
+		let account_id = account.account_id();
 		let params =
 			from_options_to_params(options, &self.rpc_client, account_id, &self.blocks).await?;
 		let call = avail::tx().nomination_pools().bond_extra(extra);
@@ -417,7 +417,7 @@ impl NominationPools {
 		pool_id: u32,
 		new_commission: Option<NewCommission>,
 		wait_for: WaitFor,
-		account: &Keypair,
+		account: &impl Signer<AvailConfig>,
 		options: Option<Options>,
 	) -> Result<PoolSetCommissionTxSuccess, String> {
 		let new_commission: NewCommissionOriginal = match new_commission {
@@ -428,7 +428,7 @@ impl NominationPools {
 			None => None,
 		};
 
-		let account_id = account.public_key().to_account_id();
+		let account_id = account.account_id();
 		let params =
 			from_options_to_params(options, &self.rpc_client, account_id, &self.blocks).await?;
 		let call = avail::tx()
@@ -464,10 +464,10 @@ impl NominationPools {
 		pool_id: u32,
 		state: State,
 		wait_for: WaitFor,
-		account: &Keypair,
+		account: &impl Signer<AvailConfig>,
 		options: Option<Options>,
 	) -> Result<PoolSetStateTxSuccess, String> {
-		let account_id = account.public_key().to_account_id();
+		let account_id = account.account_id();
 		let params =
 			from_options_to_params(options, &self.rpc_client, account_id, &self.blocks).await?;
 		let call = avail::tx().nomination_pools().set_state(pool_id, state);
@@ -497,10 +497,10 @@ impl NominationPools {
 	pub async fn claim_payout(
 		&self,
 		wait_for: WaitFor,
-		account: &Keypair,
+		account: &impl Signer<AvailConfig>,
 		options: Option<Options>,
 	) -> Result<PoolClaimPayoutTxSuccess, String> {
-		let account_id = account.public_key().to_account_id();
+		let account_id = account.account_id();
 		let params =
 			from_options_to_params(options, &self.rpc_client, account_id, &self.blocks).await?;
 		let call = avail::tx().nomination_pools().claim_payout();
@@ -531,10 +531,10 @@ impl NominationPools {
 		&self,
 		pool_id: u32,
 		wait_for: WaitFor,
-		account: &Keypair,
+		account: &impl Signer<AvailConfig>,
 		options: Option<Options>,
 	) -> Result<PoolChillTxSuccess, String> {
-		let account_id = account.public_key().to_account_id();
+		let account_id = account.account_id();
 		let params =
 			from_options_to_params(options, &self.rpc_client, account_id, &self.blocks).await?;
 		let call = avail::tx().nomination_pools().chill(pool_id);
@@ -561,10 +561,10 @@ impl NominationPools {
 		&self,
 		permission: Permission,
 		wait_for: WaitFor,
-		account: &Keypair,
+		account: &impl Signer<AvailConfig>,
 		options: Option<Options>,
 	) -> Result<PoolSetClaimPermissionTxSuccess, String> {
-		let account_id = account.public_key().to_account_id();
+		let account_id = account.account_id();
 		let params =
 			from_options_to_params(options, &self.rpc_client, account_id, &self.blocks).await?;
 		let call = avail::tx()
@@ -593,10 +593,10 @@ impl NominationPools {
 		&self,
 		pool_id: u32,
 		wait_for: WaitFor,
-		account: &Keypair,
+		account: &impl Signer<AvailConfig>,
 		options: Option<Options>,
 	) -> Result<PoolClaimCommissionTxSuccess, String> {
-		let account_id = account.public_key().to_account_id();
+		let account_id = account.account_id();
 		let params =
 			from_options_to_params(options, &self.rpc_client, account_id, &self.blocks).await?;
 		let call = avail::tx().nomination_pools().claim_commission(pool_id);
@@ -629,11 +629,11 @@ impl NominationPools {
 		&self,
 		other: &str,
 		wait_for: WaitFor,
-		account: &Keypair,
+		account: &impl Signer<AvailConfig>,
 		options: Option<Options>,
 	) -> Result<PoolClaimPayoutOtherTxSuccess, String> {
 		let other = AccountId::from_str(other).map_err(|e| e.to_string())?;
-		let account_id = account.public_key().to_account_id();
+		let account_id = account.account_id();
 		let params =
 			from_options_to_params(options, &self.rpc_client, account_id, &self.blocks).await?;
 		let call = avail::tx()
@@ -667,11 +667,11 @@ impl NominationPools {
 		member_account: &str,
 		unbonding_points: u128,
 		wait_for: WaitFor,
-		account: &Keypair,
+		account: &impl Signer<AvailConfig>,
 		options: Option<Options>,
 	) -> Result<PoolUnbondTxSuccess, String> {
 		let member_account = AccountId::from_str(member_account).map_err(|e| e.to_string())?;
-		let account_id = account.public_key().to_account_id();
+		let account_id = account.account_id();
 		let params =
 			from_options_to_params(options, &self.rpc_client, account_id, &self.blocks).await?;
 		let call = avail::tx()
@@ -705,10 +705,10 @@ impl NominationPools {
 		pool_id: u32,
 		metadata: Vec<u8>,
 		wait_for: WaitFor,
-		account: &Keypair,
+		account: &impl Signer<AvailConfig>,
 		options: Option<Options>,
 	) -> Result<PoolSetMetadataTxSuccess, String> {
-		let account_id = account.public_key().to_account_id();
+		let account_id = account.account_id();
 		let params =
 			from_options_to_params(options, &self.rpc_client, account_id, &self.blocks).await?;
 		let call = avail::tx()
@@ -738,11 +738,11 @@ impl NominationPools {
 		member_account: &str,
 		num_slashing_spans: u32,
 		wait_for: WaitFor,
-		account: &Keypair,
+		account: &impl Signer<AvailConfig>,
 		options: Option<Options>,
 	) -> Result<PoolWithdrawUnbondedTxSuccess, String> {
 		let member_account = AccountId::from_str(member_account).map_err(|e| e.to_string())?;
-		let account_id = account.public_key().to_account_id();
+		let account_id = account.account_id();
 		let params =
 			from_options_to_params(options, &self.rpc_client, account_id, &self.blocks).await?;
 		let call = avail::tx()
@@ -774,7 +774,7 @@ impl NominationPools {
 
 pub async fn tx_data_pool_nominate(
 	block_hash: BlockHash,
-	tx_hash: BlockHash,
+	tx_hash: TxHash,
 	blocks: &AvailBlocksClient,
 ) -> Result<NominationPoolsCalls::Nominate, String> {
 	let transaction =
@@ -1,5 +1,9 @@
-use crate::rpcs::Rpc;
-use crate::{AccountId, AvailBlocksClient, AvailExtrinsicParamsBuilder, BlockHash};
+use crate::rpcs::{ChainProperties, Rpc};
+use crate::utils_raw::era_window;
+use crate::{
+	AccountId, AvailBlocksClient, AvailExtrinsicParamsBuilder, BlockHash, BlockNumber, Signature,
+};
+use subxt_core::utils::Era;
 
 use super::Params;
 
@@ -9,6 +13,9 @@ pub struct Options {
 	pub mortality: Option<Mortality>,
 	pub nonce: Option<Nonce>,
 	pub tip: Option<u128>,
+	/// Metadata hash signed by the `CheckMetadataHash` extension, see
+	/// [`crate::utils_raw::metadata_hash`]. Without it the check is disabled.
+	pub metadata_hash: Option<[u8; 32]>,
 }
 impl Options {
 	pub fn new() -> Self {
@@ -17,6 +24,7 @@ impl Options {
 			mortality: None,
 			nonce: None,
 			tip: None,
+			metadata_hash: None,
 		}
 	}
 
@@ -39,8 +47,100 @@ impl Options {
 		self.tip = Some(value);
 		self
 	}
+
+	pub fn metadata_hash(mut self, value: [u8; 32]) -> Self {
+		self.metadata_hash = Some(value);
+		self
+	}
+
+	/// Sets the tip from an amount in the native token, e.g. `"0.5 AVAIL"`.
+	pub fn tip_human(self, value: &str, token: &Tip) -> Result<Self, String> {
+		Ok(self.tip(token.parse(value)?))
+	}
 }
 
+/// Converts between tips in the native token, e.g. `"0.5 AVAIL"`, and the raw amount of
+/// plancks used by the chain.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tip {
+	pub symbol: String,
+	pub decimals: u32,
+}
+impl Tip {
+	pub fn new(symbol: &str, decimals: u32) -> Self {
+		Self {
+			symbol: symbol.to_string(),
+			decimals,
+		}
+	}
+
+	/// Uses the token symbol and decimals the chain reports, falling back to AVAIL with 18
+	/// decimals for missing properties.
+	pub fn from_properties(properties: &ChainProperties) -> Self {
+		Self {
+			symbol: properties
+				.token_symbol
+				.clone()
+				.unwrap_or_else(|| String::from("AVAIL")),
+			decimals: properties.token_decimals.unwrap_or(18),
+		}
+	}
+
+	/// Parses an amount like `"0.5 AVAIL"` or `"0.5"` into plancks. If a symbol is given it has
+	/// to match the token symbol.
+	pub fn parse(&self, value: &str) -> Result<u128, String> {
+		let mut parts = value.split_whitespace();
+		let amount = parts
+			.next()
+			.ok_or_else(|| String::from("Tip amount is empty"))?;
+		if let Some(symbol) = parts.next() {
+			if !symbol.eq_ignore_ascii_case(&self.symbol) {
+				return Err(std::format!(
+					"Tip symbol {} does not match {}",
+					symbol,
+					self.symbol
+				));
+			}
+		}
+		if parts.next().is_some() {
+			return Err(std::format!("Invalid tip: {}", value));
+		}
+
+		let (integer, fraction) = amount.split_once('.').unwrap_or((amount, ""));
+		if fraction.len() > self.decimals as usize {
+			return Err(std::format!("Tip has more than {} decimals", self.decimals));
+		}
+		let digits = std::format!(
+			"{}{:0<width$}",
+			integer,
+			fraction,
+			width = self.decimals as usize
+		);
+		let is_empty = integer.is_empty() && fraction.is_empty();
+		if is_empty || !digits.bytes().all(|b| b.is_ascii_digit()) {
+			return Err(std::format!("Invalid tip: {}", value));
+		}
+
+		digits
+			.parse::<u128>()
+			.map_err(|_| std::format!("Tip is too large: {}", value))
+	}
+
+	/// Formats plancks as an amount in the native token, e.g. `"0.5 AVAIL"`.
+	pub fn format(&self, value: u128) -> String {
+		let digits = std::format!("{:0>width$}", value, width = self.decimals as usize + 1);
+		let (integer, fraction) = digits.split_at(digits.len() - self.decimals as usize);
+		let fraction = fraction.trim_end_matches('0');
+
+		match fraction.is_empty() {
+			true => std::format!("{} {}", integer, self.symbol),
+			false => std::format!("{}.{} {}", integer, fraction, self.symbol),
+		}
+	}
+}
+
+/// A period of 0 makes the transaction immortal. Immortal transactions are anchored to the
+/// genesis block and stay valid until the account nonce moves past them.
 #[derive(Debug, Clone, Copy)]
 pub struct Mortality {
 	pub period: u64,
@@ -50,6 +150,38 @@ impl Mortality {
 	pub fn new(period: u64, block_hash: Option<BlockHash>) -> Self {
 		Self { period, block_hash }
 	}
+
+	pub fn immortal() -> Self {
+		Self {
+			period: 0,
+			block_hash: None,
+		}
+	}
+
+	pub fn is_immortal(&self) -> bool {
+		self.period == 0
+	}
+
+	/// Era of a transaction built with this mortality and anchored to block `checkpoint`.
+	/// The period is rounded the same way the transaction builder rounds it.
+	pub fn era(&self, checkpoint: BlockNumber) -> Era {
+		match self.is_immortal() {
+			true => Era::Immortal,
+			false => Era::mortal(self.period, checkpoint as u64),
+		}
+	}
+
+	/// First and last block in which a transaction anchored to block `checkpoint` can be
+	/// included.
+	pub fn era_window(&self, checkpoint: BlockNumber) -> (BlockNumber, BlockNumber) {
+		era_window(self.era(checkpoint), checkpoint)
+	}
+
+	/// Whether a transaction anchored to block `checkpoint` can be included in `block`.
+	pub fn contains(&self, checkpoint: BlockNumber, block: BlockNumber) -> bool {
+		let (first, last) = self.era_window(checkpoint);
+		first <= block && block <= last
+	}
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -60,6 +192,25 @@ pub enum Nonce {
 	Custom(u32),
 }
 
+/// Signature scheme of the account signing a transaction. Sr25519 and ed25519 signatures are
+/// 64 bytes long, ECDSA signatures 65 bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SignatureScheme {
+	Sr25519,
+	Ed25519,
+	Ecdsa,
+}
+impl SignatureScheme {
+	/// Zeroed signature of this scheme, to size a transaction without signing it.
+	pub fn placeholder(&self) -> Signature {
+		match self {
+			SignatureScheme::Sr25519 => Signature::Sr25519([0u8; 64]),
+			SignatureScheme::Ed25519 => Signature::Ed25519([0u8; 64]),
+			SignatureScheme::Ecdsa => Signature::Ecdsa([0u8; 65]),
+		}
+	}
+}
+
 pub async fn from_options_to_params(
 	options: Option<Options>,
 	client: &Rpc,
@@ -73,19 +224,28 @@ pub async fn from_options_to_params(
 	let mut builder = AvailExtrinsicParamsBuilder::new();
 	builder = builder.app_id(options.app_id.unwrap_or_default());
 	builder = builder.tip(options.tip.unwrap_or_default());
+	if let Some(hash) = options.metadata_hash {
+		builder = builder.metadata_hash(hash);
+	}
 
 	let mortality = options.mortality.unwrap_or_else(|| Mortality {
 		period: 32,
 		block_hash: None,
 	});
-	let header = client.chain.get_header(mortality.block_hash);
-	let header = header.await.map_err(|e| e.to_string())?;
-	builder = builder.mortal(&header, mortality.period);
+	if !mortality.is_immortal() {
+		let header = client.chain.get_header(mortality.block_hash);
+		let header = header.await.map_err(|e| e.to_string())?;
+		builder = builder.mortal(&header, mortality.period);
+	}
 
 	if let Some(nonce) = options.nonce {
 		builder = match nonce {
 			Nonce::BestBlock => {
-				let hash = client.chain.get_block_hash(None).await.unwrap();
+				let hash = client
+					.chain
+					.get_block_hash(None)
+					.await
+					.map_err(|e| e.to_string())?;
 				let block = blocks.at(hash).await.map_err(|e| e.to_string())?;
 				let nonce = block
 					.account_nonce(&account)
@@ -94,7 +254,11 @@ pub async fn from_options_to_params(
 				builder.nonce(nonce)
 			},
 			Nonce::FinalizedBlock => {
-				let hash = client.chain.get_finalized_head().await.unwrap();
+				let hash = client
+					.chain
+					.get_finalized_head()
+					.await
+					.map_err(|e| e.to_string())?;
 				let block = blocks.at(hash).await.map_err(|e| e.to_string())?;
 				let nonce = block
 					.account_nonce(&account)
@@ -116,3 +280,100 @@ pub async fn from_options_to_params(
 
 	Ok(builder.build())
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn tip_parses_native_token_amounts() {
+		let tip = Tip::new("AVAIL", 18);
+
+		assert_eq!(tip.parse("0.5 AVAIL"), Ok(500_000_000_000_000_000));
+		assert_eq!(tip.parse("2"), Ok(2_000_000_000_000_000_000));
+		assert_eq!(tip.parse(".000000000000000001"), Ok(1));
+		assert!(tip.parse("0.5 DOT").is_err());
+		assert!(tip.parse("0.0000000000000000001").is_err());
+		assert!(tip.parse("-1").is_err());
+		assert!(tip.parse(".").is_err());
+		assert!(tip.parse("").is_err());
+	}
+
+	#[test]
+	fn tip_formats_plancks() {
+		let tip = Tip::new("AVAIL", 18);
+
+		assert_eq!(tip.format(500_000_000_000_000_000), "0.5 AVAIL");
+		assert_eq!(tip.format(2_000_000_000_000_000_000), "2 AVAIL");
+		assert_eq!(tip.format(1), "0.000000000000000001 AVAIL");
+		assert_eq!(tip.format(0), "0 AVAIL");
+	}
+
+	#[test]
+	fn mortality_era_window() {
+		let mortality = Mortality::new(32, None);
+
+		assert_eq!(mortality.era_window(100), (100, 131));
+		assert!(mortality.contains(100, 131));
+		assert!(!mortality.contains(100, 132));
+		assert!(!mortality.contains(100, 99));
+		assert_eq!(Mortality::immortal().era_window(100), (0, BlockNumber::MAX));
+	}
+
+	#[tokio::test]
+	async fn nonce_paths_agree() {
+		use crate::avail::runtime_types::avail_core::header::extension::HeaderExtension;
+		use crate::mock::MockTransport;
+		use crate::utils_raw::hex_encode_prefixed;
+		use crate::{Api, AvailHeader};
+		use codec::{Decode, Encode};
+		use serde_json::json;
+		use subxt::{backend::rpc::RpcClient, client::RuntimeVersion, config::Header, Metadata};
+
+		let genesis_hash = BlockHash::repeat_byte(0xab);
+		let header = AvailHeader {
+			parent_hash: genesis_hash,
+			number: 1,
+			state_root: BlockHash::zero(),
+			extrinsics_root: BlockHash::zero(),
+			digest: Default::default(),
+			extension: HeaderExtension::V3(Default::default()),
+		};
+		let best_hash = header.hash();
+		let alice = subxt_signer::sr25519::dev::alice();
+		let account = alice.public_key().to_account_id();
+		let address = account.to_string();
+		let account_nonce_call = json!([
+			"AccountNonceApi_account_nonce",
+			hex_encode_prefixed(&account.encode()),
+			best_hash
+		]);
+		let mock = MockTransport::new()
+			.on("chain_getBlockHash", None, json!(best_hash))
+			.on("chain_getHeader", Some(json!([best_hash])), json!(header))
+			.on("state_call", Some(account_nonce_call), json!("0x07000000"))
+			.on("system_accountNextIndex", Some(json!([address])), json!(7));
+
+		let metadata = include_bytes!("../../../avail-subxt/avail.metadata.scale");
+		let metadata = Metadata::decode(&mut metadata.as_slice()).unwrap();
+		let runtime_version = RuntimeVersion {
+			spec_version: 24,
+			transaction_version: 1,
+		};
+		let client = RpcClient::new(mock);
+		let api =
+			Api::from_rpc_client_with(genesis_hash, runtime_version, metadata, client.clone())
+				.unwrap();
+		let blocks = api.blocks();
+		let rpc = Rpc::from_client(client);
+
+		// `BestBlock` reads the nonce through the `AccountNonceApi` runtime API with the SCALE
+		// encoded account, `BestBlockAndTxPool` through `system_accountNextIndex` with the SS58
+		// address.
+		for nonce in [Nonce::BestBlock, Nonce::BestBlockAndTxPool] {
+			let options = Options::new().mortality(Mortality::immortal()).nonce(nonce);
+			let params = from_options_to_params(Some(options), &rpc, account.clone(), &blocks);
+			assert_eq!(params.await.unwrap().4 .0, Some(7));
+		}
+	}
+}
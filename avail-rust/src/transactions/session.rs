@@ -2,10 +2,10 @@ use crate::avail::runtime_types::da_runtime::primitives::SessionKeys;
 use crate::rpcs::Rpc;
 use crate::sdk::WaitFor;
 use crate::utils_raw::fetch_transaction;
-use crate::{avail, AvailBlocksClient, AvailConfig, BlockHash, TxApi};
+use crate::{avail, AvailBlocksClient, AvailConfig, BlockHash, TxApi, TxHash};
 
 use subxt::blocks::ExtrinsicEvents;
-use subxt_signer::sr25519::Keypair;
+use subxt::tx::Signer;
 
 use super::options::from_options_to_params;
 use super::{options::Options, progress_transaction_ex};
@@ -16,7 +16,7 @@ use avail::session::calls::types as SessionCalls;
 pub struct SetKeysTxSuccess {
 	pub events: ExtrinsicEvents<AvailConfig>,
 	pub tx_data: SessionCalls::SetKeys,
-	pub tx_hash: BlockHash,
+	pub tx_hash: TxHash,
 	pub tx_index: u32,
 	pub block_hash: BlockHash,
 	pub block_number: u32,
@@ -42,10 +42,10 @@ impl Session {
 		&self,
 		keys: SessionKeys,
 		wait_for: WaitFor,
-		account: &Keypair,
+		account: &impl Signer<AvailConfig>,
 		options: Option<Options>,
 	) -> Result<SetKeysTxSuccess, String> {
-		let account_id = account.public_key().to_account_id();
+		let account_id = account.account_id();
 		let params =
 			from_options_to_params(options, &self.rpc_client, account_id, &self.blocks).await?;
 		let call = avail::tx().session().set_keys(keys, vec![]);
@@ -74,7 +74,7 @@ impl Session {
 
 pub async fn tx_data_session_set_keys(
 	block_hash: BlockHash,
-	tx_hash: BlockHash,
+	tx_hash: TxHash,
 	blocks: &AvailBlocksClient,
 ) -> Result<SessionCalls::SetKeys, String> {
 	let transaction = fetch_transaction::<SessionCalls::SetKeys>(block_hash, tx_hash, blocks).await;
@@ -4,13 +4,13 @@ use crate::rpcs::Rpc;
 use crate::sdk::WaitFor;
 use crate::utils_raw::fetch_transaction;
 use crate::{
-	avail, AccountId, AvailBlocksClient, AvailConfig, BlockHash, RewardDestination, TxApi,
+	avail, AccountId, AvailBlocksClient, AvailConfig, BlockHash, RewardDestination, TxApi, TxHash,
 };
 
 use std::str::FromStr;
 use subxt::blocks::ExtrinsicEvents;
+use subxt::tx::Signer;
 use subxt_core::utils::MultiAddress;
-use subxt_signer::sr25519::Keypair;
 
 use avail::staking::calls::types as StakingCalls;
 use avail::staking::events as StakingEvents;
@@ -22,7 +22,7 @@ use super::progress_transaction_ex;
 pub struct BondTxSuccess {
 	pub event: StakingEvents::Bonded,
 	pub events: ExtrinsicEvents<AvailConfig>,
-	pub tx_hash: BlockHash,
+	pub tx_hash: TxHash,
 	pub tx_index: u32,
 	pub block_hash: BlockHash,
 	pub block_number: u32,
@@ -32,7 +32,7 @@ pub struct BondTxSuccess {
 pub struct BondExtraTxSuccess {
 	pub event: StakingEvents::Bonded,
 	pub events: ExtrinsicEvents<AvailConfig>,
-	pub tx_hash: BlockHash,
+	pub tx_hash: TxHash,
 	pub tx_index: u32,
 	pub block_hash: BlockHash,
 	pub block_number: u32,
@@ -42,7 +42,7 @@ pub struct BondExtraTxSuccess {
 pub struct ChillTxSuccess {
 	pub event: Option<StakingEvents::Chilled>,
 	pub events: ExtrinsicEvents<AvailConfig>,
-	pub tx_hash: BlockHash,
+	pub tx_hash: TxHash,
 	pub tx_index: u32,
 	pub block_hash: BlockHash,
 	pub block_number: u32,
@@ -52,7 +52,7 @@ pub struct ChillTxSuccess {
 pub struct ChillOtherTxSuccess {
 	pub event: StakingEvents::Chilled,
 	pub events: ExtrinsicEvents<AvailConfig>,
-	pub tx_hash: BlockHash,
+	pub tx_hash: TxHash,
 	pub tx_index: u32,
 	pub block_hash: BlockHash,
 	pub block_number: u32,
@@ -62,7 +62,7 @@ pub struct ChillOtherTxSuccess {
 pub struct NominateTxSuccess {
 	pub events: ExtrinsicEvents<AvailConfig>,
 	pub tx_data: StakingCalls::Nominate,
-	pub tx_hash: BlockHash,
+	pub tx_hash: TxHash,
 	pub tx_index: u32,
 	pub block_hash: BlockHash,
 	pub block_number: u32,
@@ -72,7 +72,7 @@ pub struct NominateTxSuccess {
 pub struct UnbondTxSuccess {
 	pub event: StakingEvents::Unbonded,
 	pub events: ExtrinsicEvents<AvailConfig>,
-	pub tx_hash: BlockHash,
+	pub tx_hash: TxHash,
 	pub tx_index: u32,
 	pub block_hash: BlockHash,
 	pub block_number: u32,
@@ -82,7 +82,7 @@ pub struct UnbondTxSuccess {
 pub struct ValidateTxSuccess {
 	pub event: StakingEvents::ValidatorPrefsSet,
 	pub events: ExtrinsicEvents<AvailConfig>,
-	pub tx_hash: BlockHash,
+	pub tx_hash: TxHash,
 	pub tx_index: u32,
 	pub block_hash: BlockHash,
 	pub block_number: u32,
@@ -109,10 +109,10 @@ impl Staking {
 		value: u128,
 		payee: RewardDestination,
 		wait_for: WaitFor,
-		account: &Keypair,
+		account: &impl Signer<AvailConfig>,
 		options: Option<Options>,
 	) -> Result<BondTxSuccess, String> {
-		let account_id = account.public_key().to_account_id();
+		let account_id = account.account_id();
 		let params =
 			from_options_to_params(options, &self.rpc_client, account_id, &self.blocks).await?;
 		let call = avail::tx().staking().bond(value, payee);
@@ -145,10 +145,10 @@ impl Staking {
 		&self,
 		max_additional: u128,
 		wait_for: WaitFor,
-		account: &Keypair,
+		account: &impl Signer<AvailConfig>,
 		options: Option<Options>,
 	) -> Result<BondExtraTxSuccess, String> {
-		let account_id = account.public_key().to_account_id();
+		let account_id = account.account_id();
 		let params =
 			from_options_to_params(options, &self.rpc_client, account_id, &self.blocks).await?;
 		let call = avail::tx().staking().bond_extra(max_additional);
@@ -180,10 +180,10 @@ impl Staking {
 	pub async fn chill(
 		&self,
 		wait_for: WaitFor,
-		account: &Keypair,
+		account: &impl Signer<AvailConfig>,
 		options: Option<Options>,
 	) -> Result<ChillTxSuccess, String> {
-		let account_id = account.public_key().to_account_id();
+		let account_id = account.account_id();
 		let params =
 			from_options_to_params(options, &self.rpc_client, account_id, &self.blocks).await?;
 		let call = avail::tx().staking().chill();
@@ -213,7 +213,7 @@ impl Staking {
 		&self,
 		stash: &str,
 		wait_for: WaitFor,
-		account: &Keypair,
+		account: &impl Signer<AvailConfig>,
 		options: Option<Options>,
 	) -> Result<ChillOtherTxSuccess, String> {
 		let stash = match AccountId::from_str(stash) {
@@ -221,7 +221,7 @@ impl Staking {
 			Err(error) => return Err(std::format!("{:?}", error)),
 		};
 
-		let account_id = account.public_key().to_account_id();
+		let account_id = account.account_id();
 		let params =
 			from_options_to_params(options, &self.rpc_client, account_id, &self.blocks).await?;
 		let call = avail::tx().staking().chill_other(stash);
@@ -254,7 +254,7 @@ impl Staking {
 		&self,
 		targets: &[String],
 		wait_for: WaitFor,
-		account: &Keypair,
+		account: &impl Signer<AvailConfig>,
 		options: Option<Options>,
 	) -> Result<NominateTxSuccess, String> {
 		let targets: Result<Vec<AccountId>, _> = targets
@@ -264,7 +264,7 @@ impl Staking {
 		let targets = targets.map_err(|e| std::format!("{:?}", e))?;
 		let targets = targets.into_iter().map(|a| MultiAddress::Id(a)).collect();
 
-		let account_id = account.public_key().to_account_id();
+		let account_id = account.account_id();
 		let params =
 			from_options_to_params(options, &self.rpc_client, account_id, &self.blocks).await?;
 		let call = avail::tx().staking().nominate(targets);
@@ -294,12 +294,12 @@ impl Staking {
 		&self,
 		value: u128,
 		wait_for: WaitFor,
-		account: &Keypair,
+		account: &impl Signer<AvailConfig>,
 		options: Option<Options>,
 	) -> Result<UnbondTxSuccess, String> {
 		let call = avail::tx().staking().unbond(value);
 
-		let account_id = account.public_key().to_account_id();
+		let account_id = account.account_id();
 		let params =
 			from_options_to_params(options, &self.rpc_client, account_id, &self.blocks).await?;
 		let maybe_tx_progress = self
@@ -331,7 +331,7 @@ impl Staking {
 		commission: u8,
 		blocked: bool,
 		wait_for: WaitFor,
-		account: &Keypair,
+		account: &impl Signer<AvailConfig>,
 		options: Option<Options>,
 	) -> Result<ValidateTxSuccess, String> {
 		if commission > 100 {
@@ -344,7 +344,7 @@ impl Staking {
 			blocked,
 		};
 
-		let account_id = account.public_key().to_account_id();
+		let account_id = account.account_id();
 		let params =
 			from_options_to_params(options, &self.rpc_client, account_id, &self.blocks).await?;
 		let call = avail::tx().staking().validate(perfs);
@@ -376,7 +376,7 @@ impl Staking {
 
 pub async fn tx_data_staking_nominate(
 	block_hash: BlockHash,
-	tx_hash: BlockHash,
+	tx_hash: TxHash,
 	blocks: &AvailBlocksClient,
 ) -> Result<StakingCalls::Nominate, String> {
 	let transaction =
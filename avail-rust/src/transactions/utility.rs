@@ -0,0 +1,147 @@
+use crate::api_dev::api::Call;
+use crate::rpcs::Rpc;
+use crate::sdk::WaitFor;
+use crate::utils_raw::fetch_transaction;
+use crate::{avail, AvailBlocksClient, AvailConfig, BlockHash, TxApi, TxHash};
+
+use subxt::blocks::ExtrinsicEvents;
+use subxt::tx::Signer;
+
+use super::options::from_options_to_params;
+use super::{options::Options, progress_transaction_ex};
+
+use avail::utility::calls::types as UtilityCalls;
+use avail::utility::events as UtilityEvents;
+
+#[derive(Debug)]
+pub struct BatchTxSuccess {
+	/// Set if one of the calls failed. Calls before it were executed, the rest were not.
+	pub interrupted: Option<UtilityEvents::BatchInterrupted>,
+	pub events: ExtrinsicEvents<AvailConfig>,
+	pub tx_data: UtilityCalls::Batch,
+	pub tx_hash: TxHash,
+	pub tx_index: u32,
+	pub block_hash: BlockHash,
+	pub block_number: u32,
+}
+
+#[derive(Debug)]
+pub struct BatchAllTxSuccess {
+	pub events: ExtrinsicEvents<AvailConfig>,
+	pub tx_data: UtilityCalls::BatchAll,
+	pub tx_hash: TxHash,
+	pub tx_index: u32,
+	pub block_hash: BlockHash,
+	pub block_number: u32,
+}
+
+#[derive(Clone)]
+pub struct Utility {
+	api: TxApi,
+	rpc_client: Rpc,
+	blocks: AvailBlocksClient,
+}
+
+impl Utility {
+	pub fn new(api: TxApi, rpc_client: Rpc, blocks: AvailBlocksClient) -> Self {
+		Self {
+			api,
+			rpc_client,
+			blocks,
+		}
+	}
+
+	/// Executes `calls` one after another until one of them fails.
+	pub async fn batch(
+		&self,
+		calls: Vec<Call>,
+		wait_for: WaitFor,
+		account: &impl Signer<AvailConfig>,
+		options: Option<Options>,
+	) -> Result<BatchTxSuccess, String> {
+		let account_id = account.account_id();
+		let params =
+			from_options_to_params(options, &self.rpc_client, account_id, &self.blocks).await?;
+		let call = avail::tx().utility().batch(calls);
+
+		let maybe_tx_progress = self
+			.api
+			.sign_and_submit_then_watch(&call, account, params)
+			.await;
+
+		let (events, data) =
+			progress_transaction_ex(maybe_tx_progress, wait_for, &self.blocks).await?;
+		let (block_hash, block_number, tx_hash, tx_index) = data;
+
+		let interrupted = events.find_first::<UtilityEvents::BatchInterrupted>();
+		let interrupted = interrupted.map_err(|e| e.to_string())?;
+
+		let tx_data = tx_data_utility_batch(block_hash, tx_hash, &self.blocks).await?;
+
+		Ok(BatchTxSuccess {
+			interrupted,
+			events,
+			tx_data,
+			tx_hash,
+			tx_index,
+			block_hash,
+			block_number,
+		})
+	}
+
+	/// Executes `calls` atomically. If one of them fails, none of them take effect and an
+	/// error is returned.
+	pub async fn batch_all(
+		&self,
+		calls: Vec<Call>,
+		wait_for: WaitFor,
+		account: &impl Signer<AvailConfig>,
+		options: Option<Options>,
+	) -> Result<BatchAllTxSuccess, String> {
+		let account_id = account.account_id();
+		let params =
+			from_options_to_params(options, &self.rpc_client, account_id, &self.blocks).await?;
+		let call = avail::tx().utility().batch_all(calls);
+
+		let maybe_tx_progress = self
+			.api
+			.sign_and_submit_then_watch(&call, account, params)
+			.await;
+
+		let (events, data) =
+			progress_transaction_ex(maybe_tx_progress, wait_for, &self.blocks).await?;
+		let (block_hash, block_number, tx_hash, tx_index) = data;
+
+		let tx_data = tx_data_utility_batch_all(block_hash, tx_hash, &self.blocks).await?;
+
+		Ok(BatchAllTxSuccess {
+			events,
+			tx_data,
+			tx_hash,
+			tx_index,
+			block_hash,
+			block_number,
+		})
+	}
+}
+
+pub async fn tx_data_utility_batch(
+	block_hash: BlockHash,
+	tx_hash: TxHash,
+	blocks: &AvailBlocksClient,
+) -> Result<UtilityCalls::Batch, String> {
+	let transaction = fetch_transaction::<UtilityCalls::Batch>(block_hash, tx_hash, blocks).await;
+	let transaction = transaction.map_err(|err| err.to_string())?;
+	Ok(transaction.value)
+}
+
+pub async fn tx_data_utility_batch_all(
+	block_hash: BlockHash,
+	tx_hash: TxHash,
+	blocks: &AvailBlocksClient,
+) -> Result<UtilityCalls::BatchAll, String> {
+	let transaction =
+		fetch_transaction::<UtilityCalls::BatchAll>(block_hash, tx_hash, blocks).await;
+	let transaction = transaction.map_err(|err| err.to_string())?;
+	Ok(transaction.value)
+}
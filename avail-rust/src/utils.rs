@@ -1,15 +1,36 @@
 use subxt::{
 	backend::legacy::rpc_methods::Bytes,
 	blocks::{Extrinsics, FoundExtrinsic, StaticExtrinsic},
-	tx::{TxProgress, TxStatus},
+	events::{Events, Phase},
+	tx::{PartialExtrinsic, Payload, Signer, SubmittableExtrinsic, TxProgress, TxStatus},
+	Metadata, OfflineClient,
 };
 
+use crate::avail::data_availability::calls::types::SubmitData;
+use crate::avail::runtime_types::frame_support::dispatch::DispatchInfo;
+use crate::avail::runtime_types::sp_runtime::DispatchError;
+use crate::avail::system::events as SystemEvents;
+use crate::primitives::block::extrinsics_params::CheckAppId;
 use crate::{
-	avail::runtime_types::da_runtime::primitives::SessionKeys, Api, AppUncheckedExtrinsic,
-	AvailBlocksClient, AvailConfig, BlockHash, TransactionInBlock, WaitFor,
+	avail::runtime_types::da_runtime::primitives::SessionKeys, rpcs::Rpc, AccountId, Address, Api,
+	AppUncheckedExtrinsic, AvailBlocksClient, AvailConfig, AvailExtrinsicParamsBuilder, BlockHash,
+	BlockNumber, Data, Signature, TransactionInBlock, TxHash, WaitFor, Weight,
 };
+use codec::{Decode, Encode};
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Duration;
+use subxt::client::{OfflineClientT, RuntimeVersion};
+use subxt_core::utils::Era;
+use subxt_signer::sr25519::PublicKey;
 use utils_raw::*;
 
+/// Number of recent blocks scanned by [`Util::suggested_tip`].
+pub const SUGGESTED_TIP_BLOCKS: u32 = 10;
+
+/// How often [`Util::wait_for_inclusion`] checks for a new finalized block.
+#[cfg(not(target_arch = "wasm32"))]
+pub const INCLUSION_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
 #[derive(Debug, Clone, Copy)]
 pub enum FetchTransactionError {
 	FailedToFetchBlock,
@@ -31,15 +52,87 @@ impl FetchTransactionError {
 	}
 }
 
+/// Balance and nonce of an account, taken from `System::Account` storage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct AccountBalance {
+	pub free: u128,
+	pub reserved: u128,
+	pub frozen: u128,
+	pub nonce: u32,
+}
+
+/// Everything [`utils_raw::create_partial_signed_offline`] needs to build a transaction
+/// without a node.
+#[derive(Debug, Clone, Copy)]
+pub struct OfflineParams {
+	pub genesis_hash: BlockHash,
+	pub spec_version: u32,
+	pub transaction_version: u32,
+	pub nonce: u64,
+	/// `None` makes the transaction immortal.
+	pub mortality: Option<OfflineMortality>,
+	pub tip: u128,
+	pub app_id: u32,
+}
+
+/// Block a mortal transaction is anchored to. The number and hash must belong to the same
+/// block.
+#[derive(Debug, Clone, Copy)]
+pub struct OfflineMortality {
+	pub period: u64,
+	pub block_number: BlockNumber,
+	pub block_hash: BlockHash,
+}
+
+/// Outcome of an extrinsic as reported by the `System` pallet events.
+#[derive(Debug, Clone)]
+pub enum ExtrinsicResult {
+	Success(SystemEvents::ExtrinsicSuccess),
+	Failed(SystemEvents::ExtrinsicFailed),
+}
+
+impl ExtrinsicResult {
+	pub fn is_success(&self) -> bool {
+		matches!(self, ExtrinsicResult::Success(_))
+	}
+
+	/// Weight, class and fee payment of the extrinsic, reported for successful and failed
+	/// extrinsics alike.
+	pub fn dispatch_info(&self) -> &DispatchInfo {
+		match self {
+			ExtrinsicResult::Success(event) => &event.dispatch_info,
+			ExtrinsicResult::Failed(event) => &event.dispatch_info,
+		}
+	}
+
+	/// Weight consumed by the extrinsic, taken from [`ExtrinsicResult::dispatch_info`].
+	pub fn weight(&self) -> Weight {
+		self.dispatch_info().weight.clone().into()
+	}
+
+	pub fn dispatch_error(&self) -> Option<&DispatchError> {
+		match self {
+			ExtrinsicResult::Success(_) => None,
+			ExtrinsicResult::Failed(event) => Some(&event.dispatch_error),
+		}
+	}
+}
+
 #[derive(Clone)]
 pub struct Util {
+	api: Api,
+	rpc_client: Rpc,
 	blocks_api: AvailBlocksClient,
 }
 
 impl Util {
-	pub fn new(api: Api) -> Self {
+	pub fn new(api: Api, rpc_client: Rpc) -> Self {
 		let blocks_api = api.blocks();
-		Self { blocks_api }
+		Self {
+			api,
+			rpc_client,
+			blocks_api,
+		}
 	}
 
 	pub async fn fetch_transactions(
@@ -52,11 +145,81 @@ impl Util {
 	pub async fn fetch_transaction<E: StaticExtrinsic>(
 		&self,
 		block_hash: BlockHash,
-		tx_hash: BlockHash,
+		tx_hash: TxHash,
 	) -> Result<FoundExtrinsic<AvailConfig, Api, E>, FetchTransactionError> {
 		fetch_transaction(block_hash, tx_hash, &self.blocks_api).await
 	}
 
+	pub async fn fetch_app_data(
+		&self,
+		block_hash: BlockHash,
+		app_id: u32,
+	) -> Result<Vec<Data>, FetchTransactionError> {
+		fetch_app_data(block_hash, app_id, &self.blocks_api).await
+	}
+
+	pub async fn fetch_events(&self, block_hash: BlockHash) -> Result<Events<AvailConfig>, String> {
+		fetch_events(block_hash, &self.api).await
+	}
+
+	pub async fn fetch_extrinsic_results(
+		&self,
+		block_hash: BlockHash,
+	) -> Result<Vec<(u32, ExtrinsicResult)>, String> {
+		fetch_extrinsic_results(block_hash, &self.api).await
+	}
+
+	pub async fn suggested_tip(&self, percentile: u8) -> Result<u128, String> {
+		suggested_tip(percentile, SUGGESTED_TIP_BLOCKS, &self.blocks_api).await
+	}
+
+	#[cfg(not(target_arch = "wasm32"))]
+	pub async fn wait_for_inclusion(
+		&self,
+		tx_hash: TxHash,
+		timeout: Duration,
+	) -> Result<(BlockHash, u32), String> {
+		wait_for_inclusion(tx_hash, timeout, &self.api).await
+	}
+
+	pub async fn validate_app_id(&self, app_id: u32) -> Result<(), String> {
+		validate_app_id(app_id, &self.api).await
+	}
+
+	pub async fn fetch_balance(
+		&self,
+		account: &AccountId,
+		at: Option<BlockHash>,
+	) -> Result<AccountBalance, String> {
+		fetch_balance(account, at, &self.api).await
+	}
+
+	pub async fn is_extrinsic_live(
+		&self,
+		extrinsic: &AppUncheckedExtrinsic,
+		checkpoint: BlockNumber,
+	) -> Result<bool, String> {
+		is_extrinsic_live(extrinsic, checkpoint, &self.rpc_client).await
+	}
+
+	pub async fn metadata_hash(
+		&self,
+		decimals: u8,
+		token_symbol: &str,
+	) -> Result<[u8; 32], String> {
+		metadata_hash(decimals, token_symbol, &self.api).await
+	}
+
+	pub async fn replace_extrinsic(
+		&self,
+		original: &AppUncheckedExtrinsic,
+		tip: u128,
+		wait_for: WaitFor,
+		account: &impl Signer<AvailConfig>,
+	) -> Result<TransactionInBlock, String> {
+		replace_extrinsic(original, tip, wait_for, account, &self.api).await
+	}
+
 	pub async fn progress_transaction(
 		&self,
 		maybe_tx_progress: Result<TxProgress<AvailConfig, Api>, subxt::Error>,
@@ -65,6 +228,23 @@ impl Util {
 		progress_transaction(maybe_tx_progress, wait_for).await
 	}
 
+	#[cfg(not(target_arch = "wasm32"))]
+	pub async fn progress_transaction_with_timeout(
+		&self,
+		maybe_tx_progress: Result<TxProgress<AvailConfig, Api>, subxt::Error>,
+		wait_for: WaitFor,
+		timeout: Duration,
+	) -> Result<TransactionInBlock, String> {
+		progress_transaction_with_timeout(maybe_tx_progress, wait_for, timeout).await
+	}
+
+	pub async fn submit_and_watch(
+		&self,
+		extrinsic: Vec<u8>,
+	) -> Result<TxProgress<AvailConfig, Api>, subxt::Error> {
+		submit_and_watch(extrinsic, &self.api).await
+	}
+
 	pub fn decode_raw_block_rpc_extrinsics(
 		&self,
 		extrinsics: Vec<Bytes>,
@@ -72,6 +252,14 @@ impl Util {
 		decode_raw_block_rpc_extrinsics(extrinsics)
 	}
 
+	pub fn dispatch_error_to_string(&self, error: &DispatchError) -> String {
+		dispatch_error_to_string(error, &self.api.metadata())
+	}
+
+	pub fn account_id_from_ss58(&self, address: &str) -> Result<(AccountId, u16), String> {
+		account_id_from_ss58(address)
+	}
+
 	pub fn deconstruct_session_keys(&self, session_keys: Vec<u8>) -> Result<SessionKeys, String> {
 		deconstruct_session_keys(session_keys)
 	}
@@ -138,7 +326,7 @@ pub mod utils_raw {
 
 	pub async fn fetch_transaction<E: StaticExtrinsic>(
 		block_hash: BlockHash,
-		tx_hash: BlockHash,
+		tx_hash: TxHash,
 		blocks_api: &AvailBlocksClient,
 	) -> Result<FoundExtrinsic<AvailConfig, Api, E>, FetchTransactionError> {
 		let extrinsics = fetch_transactions(block_hash, blocks_api).await?;
@@ -163,14 +351,284 @@ pub mod utils_raw {
 		Err(FetchTransactionError::TransactionNotFoundInTheBlock)
 	}
 
+	/// Returns the data of every `submit_data` call submitted under `app_id` in the block,
+	/// in extrinsic order.
+	pub async fn fetch_app_data(
+		block_hash: BlockHash,
+		app_id: u32,
+		blocks_api: &AvailBlocksClient,
+	) -> Result<Vec<Data>, FetchTransactionError> {
+		let extrinsics = fetch_transactions(block_hash, blocks_api).await?;
+
+		let mut data = Vec::new();
+		for ext in extrinsics.find::<SubmitData>() {
+			let ext = match ext {
+				Ok(e) => e,
+				Err(_) => continue,
+			};
+			let ext_app_id = ext
+				.details
+				.signed_extensions()
+				.and_then(|e| e.find::<CheckAppId>().ok().flatten())
+				.map(|id| id.0)
+				.unwrap_or_default();
+			if ext_app_id == app_id {
+				data.push(ext.value.data);
+			}
+		}
+
+		Ok(data)
+	}
+
+	pub async fn fetch_events(
+		block_hash: BlockHash,
+		api: &Api,
+	) -> Result<Events<AvailConfig>, String> {
+		api.events().at(block_hash).await.map_err(|e| e.to_string())
+	}
+
+	/// Returns the extrinsic index and result of every extrinsic in the block, in
+	/// extrinsic order.
+	pub async fn fetch_extrinsic_results(
+		block_hash: BlockHash,
+		api: &Api,
+	) -> Result<Vec<(u32, ExtrinsicResult)>, String> {
+		let events = fetch_events(block_hash, api).await?;
+
+		let mut results = Vec::new();
+		for event in events.iter() {
+			let event = event.map_err(|e| e.to_string())?;
+			let Phase::ApplyExtrinsic(index) = event.phase() else {
+				continue;
+			};
+
+			let success = event
+				.as_event::<SystemEvents::ExtrinsicSuccess>()
+				.map_err(|e| e.to_string())?;
+			if let Some(success) = success {
+				results.push((index, ExtrinsicResult::Success(success)));
+				continue;
+			}
+
+			let failed = event
+				.as_event::<SystemEvents::ExtrinsicFailed>()
+				.map_err(|e| e.to_string())?;
+			if let Some(failed) = failed {
+				results.push((index, ExtrinsicResult::Failed(failed)));
+			}
+		}
+
+		Ok(results)
+	}
+
+	/// Returns the tip at `percentile` (0 to 100) of all signed extrinsics in the last
+	/// `block_count` blocks. If there are none, 0 is returned.
+	pub async fn suggested_tip(
+		percentile: u8,
+		block_count: u32,
+		blocks_api: &AvailBlocksClient,
+	) -> Result<u128, String> {
+		let mut block = blocks_api.at_latest().await.map_err(|e| e.to_string())?;
+
+		let mut tips = Vec::new();
+		for _ in 0..block_count {
+			let extrinsics = block.extrinsics().await.map_err(|e| e.to_string())?;
+			for ext in extrinsics.iter() {
+				let ext = ext.map_err(|e| e.to_string())?;
+				if let Some(tip) = ext.signed_extensions().and_then(|e| e.tip()) {
+					tips.push(tip);
+				}
+			}
+
+			let header = block.header();
+			if header.number == 0 {
+				break;
+			}
+			block = blocks_api
+				.at(header.parent_hash)
+				.await
+				.map_err(|e| e.to_string())?;
+		}
+
+		if tips.is_empty() {
+			return Ok(0);
+		}
+		tips.sort_unstable();
+		let percentile = percentile.min(100) as usize;
+		Ok(tips[(tips.len() - 1) * percentile / 100])
+	}
+
+	/// Polls finalized blocks until one of them contains the extrinsic `tx_hash` and returns
+	/// the block hash and extrinsic index. Only blocks finalized after the call are scanned,
+	/// together with the finalized block at the time of the call. Works without subscriptions.
+	#[cfg(not(target_arch = "wasm32"))]
+	pub async fn wait_for_inclusion(
+		tx_hash: TxHash,
+		timeout: Duration,
+		api: &Api,
+	) -> Result<(BlockHash, u32), String> {
+		let backend = api.backend();
+		let deadline = tokio::time::Instant::now() + timeout;
+
+		let mut last_checked: Option<BlockHash> = None;
+		loop {
+			let finalized = backend
+				.latest_finalized_block_ref()
+				.await
+				.map_err(|e| e.to_string())?
+				.hash();
+
+			// Walk back to the last block that was already checked so no block is skipped.
+			let mut pending = Vec::new();
+			let mut hash = finalized;
+			while Some(hash) != last_checked {
+				pending.push(hash);
+				if last_checked.is_none() {
+					break;
+				}
+				let header = backend
+					.block_header(hash)
+					.await
+					.map_err(|e| e.to_string())?;
+				let Some(header) = header else {
+					return Err(String::from("Failed to fetch block header"));
+				};
+				hash = header.parent_hash;
+			}
+
+			for block_hash in pending.into_iter().rev() {
+				if let Some(index) = find_extrinsic(block_hash, tx_hash, api).await? {
+					return Ok((block_hash, index));
+				}
+			}
+			last_checked = Some(finalized);
+
+			if tokio::time::Instant::now() >= deadline {
+				return Err(String::from(
+					"Timed out waiting for the transaction to be included",
+				));
+			}
+			tokio::time::sleep(INCLUSION_POLL_INTERVAL).await;
+		}
+	}
+
+	/// Returns the index of the extrinsic `tx_hash` in the block, if it is there.
+	pub async fn find_extrinsic(
+		block_hash: BlockHash,
+		tx_hash: TxHash,
+		api: &Api,
+	) -> Result<Option<u32>, String> {
+		let body = api
+			.backend()
+			.block_body(block_hash)
+			.await
+			.map_err(|e| e.to_string())?;
+		let Some(body) = body else {
+			return Err(String::from("Failed to fetch block"));
+		};
+
+		for (index, extrinsic) in body.iter().enumerate() {
+			let Ok(extrinsic) = AppUncheckedExtrinsic::decode(&mut extrinsic.as_slice()) else {
+				continue;
+			};
+			if extrinsic.hash() == tx_hash {
+				return Ok(Some(index as u32));
+			}
+		}
+
+		Ok(None)
+	}
+
+	/// Fails if no application with `app_id` has been registered on chain. Use it before
+	/// submitting data to catch a misconfigured app id without paying fees.
+	pub async fn validate_app_id(app_id: u32, api: &Api) -> Result<(), String> {
+		let address = crate::avail::storage().data_availability().next_app_id();
+		let next_app_id = api
+			.storage()
+			.at_latest()
+			.await
+			.map_err(|e| e.to_string())?
+			.fetch_or_default(&address)
+			.await
+			.map_err(|e| e.to_string())?;
+
+		if app_id >= next_app_id.0 {
+			return Err(std::format!("Application id {} is not registered", app_id));
+		}
+
+		Ok(())
+	}
+
+	/// Replaces a pending transaction with one executing the same call with the same nonce
+	/// but a higher `tip`. The transaction pool keeps only one transaction per account and
+	/// nonce, and lets a new one replace a pending one only if it has a higher priority, so the
+	/// tip has to be higher than the original one. The app id and the mortality period of the
+	/// original are kept; the new transaction is anchored to the latest finalized block. Fails
+	/// if `account` is not the signer of the original.
+	pub async fn replace_extrinsic(
+		original: &AppUncheckedExtrinsic,
+		tip: u128,
+		wait_for: WaitFor,
+		account: &impl Signer<AvailConfig>,
+		api: &Api,
+	) -> Result<TransactionInBlock, String> {
+		let (Some(nonce), Some(original_tip)) = (original.nonce(), original.tip()) else {
+			return Err(String::from("Only signed extrinsics can be replaced"));
+		};
+		let account_id = account.account_id();
+		if original.address() != Some(&Address::Id(account_id.clone())) {
+			return Err(std::format!(
+				"Only the signer can replace an extrinsic. Account: {}",
+				account_id
+			));
+		}
+		if tip <= original_tip {
+			return Err(std::format!(
+				"Tip must be higher than the original tip. Tip: {}, Original tip: {}",
+				tip,
+				original_tip
+			));
+		}
+
+		let mut builder = AvailExtrinsicParamsBuilder::new()
+			.app_id(original.app_id().0 .0)
+			.nonce(nonce as u64)
+			.tip(tip);
+		if let Some(Era::Mortal { period, .. }) = original.era() {
+			let block = api.blocks().at_latest().await.map_err(|e| e.to_string())?;
+			builder = builder.mortal(block.header(), period);
+		}
+
+		let call = EncodedCall(original.function.encode());
+		let maybe_tx_progress = api
+			.tx()
+			.sign_and_submit_then_watch(&call, account, builder.build())
+			.await;
+		progress_transaction(maybe_tx_progress, wait_for).await
+	}
+
+	/// Call that is already SCALE encoded, e.g. the call of a decoded extrinsic.
+	struct EncodedCall(Vec<u8>);
+
+	impl Payload for EncodedCall {
+		fn encode_call_data_to(
+			&self,
+			_metadata: &subxt_core::Metadata,
+			out: &mut Vec<u8>,
+		) -> Result<(), subxt_core::Error> {
+			out.extend_from_slice(&self.0);
+			Ok(())
+		}
+	}
+
 	pub async fn progress_transaction(
 		maybe_tx_progress: Result<TxProgress<AvailConfig, Api>, subxt::Error>,
 		wait_for: WaitFor,
 	) -> Result<TransactionInBlock, String> {
-		if let Err(error) = maybe_tx_progress {
-			return Err(error.to_string());
-		}
-		let mut tx_progress = maybe_tx_progress.unwrap();
+		let mut tx_progress = match maybe_tx_progress {
+			Ok(tx_progress) => tx_progress,
+			Err(error) => return Err(error.to_string()),
+		};
 
 		while let Some(tx_status) = tx_progress.next().await {
 			let tx_status = match tx_status {
@@ -199,6 +657,35 @@ pub mod utils_raw {
 		Err(String::from("Something went wrong."))
 	}
 
+	/// Same as [`progress_transaction`] but gives up once `timeout` has passed. Giving up
+	/// closes the status subscription; the transaction may still be included afterwards.
+	#[cfg(not(target_arch = "wasm32"))]
+	pub async fn progress_transaction_with_timeout(
+		maybe_tx_progress: Result<TxProgress<AvailConfig, Api>, subxt::Error>,
+		wait_for: WaitFor,
+		timeout: Duration,
+	) -> Result<TransactionInBlock, String> {
+		let progress = progress_transaction(maybe_tx_progress, wait_for);
+		match tokio::time::timeout(timeout, progress).await {
+			Ok(result) => result,
+			Err(_) => Err(std::format!(
+				"Timed out waiting for the transaction after {:?}",
+				timeout
+			)),
+		}
+	}
+
+	/// Submits an already signed and encoded extrinsic and watches its status.
+	/// The result can be passed to [`progress_transaction`].
+	pub async fn submit_and_watch(
+		extrinsic: Vec<u8>,
+		api: &Api,
+	) -> Result<TxProgress<AvailConfig, Api>, subxt::Error> {
+		SubmittableExtrinsic::from_bytes(api.clone(), extrinsic)
+			.submit_and_watch()
+			.await
+	}
+
 	pub fn decode_raw_block_rpc_extrinsics(
 		extrinsics: Vec<Bytes>,
 	) -> Result<Vec<AppUncheckedExtrinsic>, String> {
@@ -210,6 +697,280 @@ pub mod utils_raw {
 		extrinsics
 	}
 
+	/// Resolves `DispatchError::Module` into `Pallet::Error` using the runtime metadata.
+	/// Every other variant is formatted as it is.
+	pub fn dispatch_error_to_string(error: &DispatchError, metadata: &subxt::Metadata) -> String {
+		let DispatchError::Module(module_error) = error else {
+			return std::format!("{:?}", error);
+		};
+
+		let pallet = metadata.pallet_by_index(module_error.index);
+		let variant = pallet
+			.as_ref()
+			.and_then(|p| p.error_variant_by_index(module_error.error[0]));
+		match (pallet, variant) {
+			(Some(pallet), Some(variant)) => std::format!("{}::{}", pallet.name(), variant.name),
+			_ => std::format!("{:?}", error),
+		}
+	}
+
+	/// Reads the balance of `account` at block `at`, or at the latest finalized block if `at`
+	/// is `None`. Accounts that do not exist have a zero balance.
+	pub async fn fetch_balance(
+		account: &AccountId,
+		at: Option<BlockHash>,
+		api: &Api,
+	) -> Result<AccountBalance, String> {
+		let storage = match at {
+			Some(block_hash) => api.storage().at(block_hash),
+			None => api.storage().at_latest().await.map_err(|e| e.to_string())?,
+		};
+
+		let address = crate::avail::storage().system().account(account.clone());
+		let info = storage
+			.fetch_or_default(&address)
+			.await
+			.map_err(|e| e.to_string())?;
+
+		Ok(AccountBalance {
+			free: info.data.free,
+			reserved: info.data.reserved,
+			frozen: info.data.frozen,
+			nonce: info.nonce,
+		})
+	}
+
+	/// Checks that a signed extrinsic has not expired relative to the current best block, so a
+	/// stale extrinsic is not submitted. `checkpoint` is the number of the block its mortality
+	/// was anchored to. Immortal and unsigned extrinsics are always live.
+	pub async fn is_extrinsic_live(
+		extrinsic: &AppUncheckedExtrinsic,
+		checkpoint: BlockNumber,
+		client: &Rpc,
+	) -> Result<bool, String> {
+		let Some(era) = extrinsic.era() else {
+			return Ok(true);
+		};
+
+		let best_block_number = client
+			.chain
+			.best_block_number()
+			.await
+			.map_err(|e| e.to_string())?;
+
+		Ok(is_era_live(era, checkpoint, best_block_number))
+	}
+
+	/// Metadata hash of the runtime `api` signs for, to enable the `CheckMetadataHash` extension
+	/// through [`crate::Options::metadata_hash`]. `decimals` and `token_symbol` have to be the
+	/// ones the runtime was built with, e.g. `18` and `"AVAIL"`.
+	pub async fn metadata_hash(
+		decimals: u8,
+		token_symbol: &str,
+		api: &Api,
+	) -> Result<[u8; 32], String> {
+		let backend = api.backend();
+		let block_hash = backend
+			.latest_finalized_block_ref()
+			.await
+			.map_err(|e| e.to_string())?
+			.hash();
+
+		// `Core_version` returns the runtime version starting with the spec name, the
+		// implementation name, the authoring version and then the spec version.
+		let version = backend
+			.call("Core_version", None, block_hash)
+			.await
+			.map_err(|e| e.to_string())?;
+		let (spec_name, _, _, spec_version) =
+			<(String, String, u32, u32)>::decode(&mut version.as_slice())
+				.map_err(|e| e.to_string())?;
+		if spec_version != api.runtime_version().spec_version {
+			return Err(String::from(
+				"The runtime was upgraded, refresh the metadata before computing its hash",
+			));
+		}
+
+		let metadata = api.metadata();
+		crate::metadata_hash::compute(&metadata, &spec_name, spec_version, decimals, token_symbol)
+	}
+
+	/// Whether `version` is a later runtime than `other`, i.e. it has a higher spec version,
+	/// or the same spec version and a higher transaction version.
+	pub fn is_runtime_newer(version: &RuntimeVersion, other: &RuntimeVersion) -> bool {
+		(version.spec_version, version.transaction_version)
+			> (other.spec_version, other.transaction_version)
+	}
+
+	/// Whether a transaction with `era`, anchored to block `checkpoint`, is still valid at
+	/// block `current`.
+	pub fn is_era_live(era: Era, checkpoint: BlockNumber, current: BlockNumber) -> bool {
+		current <= era_window(era, checkpoint).1
+	}
+
+	/// First and last block in which a transaction with `era`, anchored to block `checkpoint`,
+	/// can be included. Mortal eras with a period below 4, which the runtime never creates,
+	/// are treated as immortal.
+	pub fn era_window(era: Era, checkpoint: BlockNumber) -> (BlockNumber, BlockNumber) {
+		let Era::Mortal { period, phase } = era else {
+			return (0, BlockNumber::MAX);
+		};
+		if period < 4 {
+			return (0, BlockNumber::MAX);
+		}
+
+		let checkpoint = checkpoint as u64;
+		let birth = (checkpoint.max(phase) - phase) / period * period + phase;
+		let last = (birth + period - 1).min(BlockNumber::MAX as u64);
+		(birth as BlockNumber, last as BlockNumber)
+	}
+
+	/// Builds a transaction for signing without any network access, e.g. on an air-gapped
+	/// machine. `metadata` has to match the runtime the transaction is submitted to; it can be
+	/// SCALE encoded on an online machine and decoded with `Metadata::decode`. The signed
+	/// transaction can then be submitted from an online machine with [`submit_and_watch`].
+	pub fn create_partial_signed_offline<Call: Payload>(
+		call: &Call,
+		metadata: Metadata,
+		params: OfflineParams,
+	) -> Result<PartialExtrinsic<AvailConfig, OfflineClient<AvailConfig>>, String> {
+		let runtime_version = RuntimeVersion {
+			spec_version: params.spec_version,
+			transaction_version: params.transaction_version,
+		};
+		let client = OfflineClient::new(params.genesis_hash, runtime_version, metadata);
+
+		let mut builder = AvailExtrinsicParamsBuilder::new()
+			.nonce(params.nonce)
+			.tip(params.tip)
+			.app_id(params.app_id);
+		if let Some(mortality) = params.mortality {
+			builder = builder.mortal_unchecked(
+				mortality.block_number as u64,
+				mortality.block_hash,
+				mortality.period,
+			);
+		}
+
+		client
+			.tx()
+			.create_partial_signed_offline(call, builder.build())
+			.map_err(|e| e.to_string())
+	}
+
+	/// Hex encodes `value` with a `0x` prefix, the format used by the node's RPC.
+	pub fn hex_encode_prefixed(value: &[u8]) -> String {
+		std::format!("0x{}", hex::encode(value))
+	}
+
+	/// Decodes a `0x` prefixed hex string. Strings without the prefix are rejected.
+	pub fn hex_decode_prefixed(value: &str) -> Result<Vec<u8>, String> {
+		let Some(value) = value.strip_prefix("0x") else {
+			return Err(String::from("Hex string is missing the 0x prefix"));
+		};
+		hex::decode(value).map_err(|e| e.to_string())
+	}
+
+	/// Sr25519 signature from the 64 raw bytes returned by an external signer.
+	pub fn signature_from_bytes(value: &[u8]) -> Result<Signature, String> {
+		let value: [u8; 64] = value.try_into().map_err(|_| {
+			std::format!("Signature must be 64 bytes long. Actual: {}", value.len())
+		})?;
+		Ok(Signature::Sr25519(value))
+	}
+
+	/// Same as [`signature_from_bytes`] but `value` is `0x` prefixed hex.
+	pub fn signature_from_hex(value: &str) -> Result<Signature, String> {
+		signature_from_bytes(&hex_decode_prefixed(value)?)
+	}
+
+	/// Sr25519 public key from its 32 raw bytes.
+	pub fn public_key_from_bytes(value: &[u8]) -> Result<PublicKey, String> {
+		let value: [u8; 32] = value.try_into().map_err(|_| {
+			std::format!("Public key must be 32 bytes long. Actual: {}", value.len())
+		})?;
+		Ok(PublicKey(value))
+	}
+
+	/// Same as [`public_key_from_bytes`] but `value` is `0x` prefixed hex.
+	pub fn public_key_from_hex(value: &str) -> Result<PublicKey, String> {
+		public_key_from_bytes(&hex_decode_prefixed(value)?)
+	}
+
+	/// Checks that `signature` is a valid sr25519 signature of `payload`. Payloads longer than
+	/// 256 bytes are hashed with blake2-256 first, same as when signing, so both the raw
+	/// payload and [`PartialExtrinsic::signer_payload`] can be passed.
+	pub fn verify_signature(public_key: &PublicKey, payload: &[u8], signature: &Signature) -> bool {
+		let Signature::Sr25519(signature) = signature else {
+			return false;
+		};
+		let signature = subxt_signer::sr25519::Signature(*signature);
+
+		if payload.len() > 256 {
+			let hash = sp_core::hashing::blake2_256(payload);
+			return subxt_signer::sr25519::verify(&signature, hash, public_key);
+		}
+		subxt_signer::sr25519::verify(&signature, payload, public_key)
+	}
+
+	/// Same as [`PartialExtrinsic::sign_with_address_and_signature`] but an externally produced
+	/// `signature` is first verified against the signing payload. An invalid signature is
+	/// reported here instead of being rejected by the node after submission.
+	pub fn sign_with_verified_signature<T: OfflineClientT<AvailConfig>>(
+		partial: &PartialExtrinsic<AvailConfig, T>,
+		account_id: &AccountId,
+		signature: &Signature,
+	) -> Result<SubmittableExtrinsic<AvailConfig, T>, String> {
+		let public_key = PublicKey(account_id.0);
+		if !verify_signature(&public_key, &partial.signer_payload(), signature) {
+			return Err(String::from("Signature does not match the signing payload"));
+		}
+
+		Ok(partial.sign_with_address_and_signature(&account_id.clone().into(), signature))
+	}
+
+	/// 20 byte EVM address of `account_id`, made of its first 20 bytes. This is the same
+	/// truncation EVM compatible Substrate runtimes use to map native accounts to H160.
+	#[cfg(feature = "eth-compat")]
+	pub fn account_id_to_eth_address(account_id: &AccountId) -> [u8; 20] {
+		let mut address = [0u8; 20];
+		address.copy_from_slice(&account_id.0[..20]);
+		address
+	}
+
+	/// Decodes an SS58 address into the account id and the network prefix it was encoded
+	/// with. The checksum is validated.
+	pub fn account_id_from_ss58(address: &str) -> Result<(AccountId, u16), String> {
+		use base58::FromBase58;
+
+		let data = address
+			.from_base58()
+			.map_err(|e| std::format!("Invalid base58: {:?}", e))?;
+
+		let (prefix, prefix_len) = match data.first() {
+			Some(0..=63) => (data[0] as u16, 1),
+			Some(64..=127) if data.len() > 1 => {
+				let lower = (data[0] << 2) | (data[1] >> 6);
+				let upper = data[1] & 0b0011_1111;
+				((lower as u16) | ((upper as u16) << 8), 2)
+			},
+			_ => return Err(String::from("Invalid SS58 prefix")),
+		};
+		if data.len() != prefix_len + 32 + 2 {
+			return Err(String::from("Invalid SS58 address length"));
+		}
+
+		let (body, checksum) = data.split_at(prefix_len + 32);
+		let hash = sp_core::hashing::blake2_512(&[b"SS58PRE", body].concat());
+		if checksum != &hash[..2] {
+			return Err(String::from("Invalid SS58 checksum"));
+		}
+
+		let mut account_id = [0u8; 32];
+		account_id.copy_from_slice(&body[prefix_len..]);
+		Ok((AccountId::from(account_id), prefix))
+	}
+
 	pub fn deconstruct_session_keys(session_keys: Vec<u8>) -> Result<SessionKeys, String> {
 		use crate::avail::runtime_types::sp_core::ed25519::Public as EDPublic;
 		use crate::avail::runtime_types::sp_core::sr25519::Public as SRPublic;
@@ -239,3 +1000,187 @@ pub mod utils_raw {
 		})
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::utils_raw::{
+		account_id_from_ss58, era_window, hex_decode_prefixed, hex_encode_prefixed, is_era_live,
+		is_runtime_newer, public_key_from_hex, signature_from_bytes, verify_signature,
+	};
+	use subxt::client::RuntimeVersion;
+	use subxt_core::utils::Era;
+
+	#[test]
+	fn account_id_from_ss58_decodes_prefix_and_key() {
+		let (account_id, prefix) =
+			account_id_from_ss58("5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY").unwrap();
+
+		assert_eq!(prefix, 42);
+		assert_eq!(
+			hex::encode(account_id.0),
+			"d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d"
+		);
+	}
+
+	#[test]
+	fn account_id_from_ss58_rejects_bad_checksum() {
+		let result = account_id_from_ss58("5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQZ");
+
+		assert!(result.is_err());
+	}
+
+	#[test]
+	fn is_era_live_until_period_ends() {
+		let era = Era::mortal(32, 100);
+
+		assert!(is_era_live(era, 100, 100));
+		assert!(is_era_live(era, 100, 131));
+		assert!(!is_era_live(era, 100, 132));
+	}
+
+	#[test]
+	fn is_era_live_for_immortal() {
+		assert!(is_era_live(Era::Immortal, 0, u32::MAX));
+	}
+
+	#[test]
+	fn era_window_of_invalid_period_is_immortal() {
+		let era = Era::Mortal {
+			period: 0,
+			phase: 0,
+		};
+
+		assert_eq!(era_window(era, 100), (0, u32::MAX));
+		assert!(is_era_live(era, 100, u32::MAX));
+	}
+
+	#[test]
+	fn is_runtime_newer_compares_spec_then_transaction_version() {
+		let version = |spec_version, transaction_version| RuntimeVersion {
+			spec_version,
+			transaction_version,
+		};
+
+		assert!(is_runtime_newer(&version(2, 1), &version(1, 5)));
+		assert!(is_runtime_newer(&version(1, 2), &version(1, 1)));
+		assert!(!is_runtime_newer(&version(1, 1), &version(1, 1)));
+		assert!(!is_runtime_newer(&version(1, 5), &version(2, 1)));
+	}
+
+	#[test]
+	fn hex_prefixed_round_trip() {
+		assert_eq!(hex_encode_prefixed(&[]), "0x");
+		assert_eq!(hex_encode_prefixed(&[0x01, 0xab]), "0x01ab");
+		assert_eq!(hex_decode_prefixed("0x"), Ok(vec![]));
+		assert_eq!(hex_decode_prefixed("0x01AB"), Ok(vec![0x01, 0xab]));
+	}
+
+	#[test]
+	fn hex_decode_prefixed_rejects_malformed_input() {
+		assert!(hex_decode_prefixed("01ab").is_err());
+		assert!(hex_decode_prefixed("0x1ab").is_err());
+		assert!(hex_decode_prefixed("0xzz").is_err());
+		assert!(hex_decode_prefixed("").is_err());
+	}
+
+	#[test]
+	fn signature_from_bytes_validates_length() {
+		assert!(matches!(
+			signature_from_bytes(&[7u8; 64]),
+			Ok(crate::Signature::Sr25519(bytes)) if bytes == [7u8; 64]
+		));
+		assert!(signature_from_bytes(&[7u8; 63]).is_err());
+		assert!(signature_from_bytes(&[7u8; 65]).is_err());
+	}
+
+	#[test]
+	fn public_key_from_hex_validates_length() {
+		let key = "0xd43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d";
+
+		assert_eq!(public_key_from_hex(key).unwrap().0[0], 0xd4);
+		assert!(public_key_from_hex("0xd435").is_err());
+	}
+
+	#[test]
+	fn verify_signature_hashes_long_payloads() {
+		use core::str::FromStr;
+		use subxt_signer::{sr25519::Keypair, SecretUri};
+
+		let keypair = Keypair::from_uri(&SecretUri::from_str("//Alice").unwrap()).unwrap();
+		let public_key = keypair.public_key();
+		let sign = |message: &[u8]| crate::Signature::Sr25519(keypair.sign(message).0);
+
+		let short = [1u8; 32];
+		assert!(verify_signature(&public_key, &short, &sign(&short)));
+
+		let long = [1u8; 300];
+		let hash = sp_core::hashing::blake2_256(&long);
+		assert!(verify_signature(&public_key, &long, &sign(&hash)));
+		assert!(!verify_signature(&public_key, &long, &sign(&long)));
+		assert!(!verify_signature(&public_key, &short, &sign(&[2u8; 32])));
+	}
+
+	#[test]
+	fn signer_payload_over_256_bytes_is_hashed() {
+		use super::utils_raw::{create_partial_signed_offline, sign_with_verified_signature};
+		use crate::avail::runtime_types::bounded_collections::bounded_vec::BoundedVec;
+		use crate::{BlockHash, OfflineParams, Signature};
+		use codec::{Compact, Decode, Encode};
+		use subxt::Metadata;
+
+		let metadata = include_bytes!("../../avail-subxt/avail.metadata.scale");
+		let metadata = Metadata::decode(&mut metadata.as_slice()).unwrap();
+		let genesis_hash = BlockHash::repeat_byte(0xab);
+		let params = OfflineParams {
+			genesis_hash,
+			spec_version: 24,
+			transaction_version: 1,
+			nonce: 0,
+			mortality: None,
+			tip: 0,
+			app_id: 0,
+		};
+		let alice = subxt_signer::sr25519::dev::alice();
+		let account_id = alice.public_key().to_account_id();
+
+		// 176 bytes of data make a payload of exactly 256 bytes, which is signed as is.
+		for len in [176usize, 177] {
+			let data = vec![1u8; len];
+			let mut raw = (29u8, 1u8, Compact(len as u32)).encode();
+			raw.extend(&data);
+			raw.extend((0u8, Compact(0u32), Compact(0u128), Compact(0u32)).encode());
+			raw.extend((24u32, 1u32, genesis_hash, genesis_hash).encode());
+
+			let call = crate::avail::tx()
+				.data_availability()
+				.submit_data(BoundedVec(data));
+			let partial =
+				create_partial_signed_offline(&call.unvalidated(), metadata.clone(), params)
+					.unwrap();
+			let payload = partial.signer_payload();
+			if raw.len() > 256 {
+				assert_eq!(payload, sp_core::hashing::blake2_256(&raw).to_vec());
+			} else {
+				assert_eq!(payload, raw);
+			}
+
+			let signature = Signature::Sr25519(alice.sign(&payload).0);
+			assert!(verify_signature(&alice.public_key(), &payload, &signature));
+			assert!(sign_with_verified_signature(&partial, &account_id, &signature).is_ok());
+		}
+	}
+
+	#[cfg(feature = "eth-compat")]
+	#[test]
+	fn account_id_to_eth_address_truncates() {
+		use super::utils_raw::account_id_to_eth_address;
+
+		let (alice, _) =
+			account_id_from_ss58("5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY").unwrap();
+
+		assert_eq!(
+			hex::encode(account_id_to_eth_address(&alice)),
+			"d43593c715fdd31c61141abd04a99fd6822c8558"
+		);
+	}
+}